@@ -1,8 +1,13 @@
 //! Backend configuration types and persistence.
 
+use crate::commands::config::{
+    GlobalConfig, GlobalDefaultsConfig, GlobalGitConfig, GlobalInfrastructureConfig,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 /// Backend type enumeration.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -12,6 +17,9 @@ pub enum BackendType {
     LocalPython,
     /// Docker container (ghcr.io/ao-cyber-systems/devflow, TCP mode)
     Docker,
+    /// Docker Compose project, for devflow plus sidecar services (db, cache,
+    /// worker) managed as a unit (TCP mode)
+    DockerCompose,
     /// WSL2 service (Windows only, TCP mode)
     Wsl2,
     /// Remote DevFlow instance (TCP mode)
@@ -41,6 +49,42 @@ pub struct BackendConfig {
     pub remote_port: Option<u16>,
     /// Whether to auto-start the backend on app launch
     pub auto_start: bool,
+    /// Workspace bind mounts (for Docker), each `host_path:container_path[:ro|rw]`
+    #[serde(default)]
+    pub docker_mounts: Vec<String>,
+    /// `--memory` limit (for Docker), e.g. "2g"
+    pub docker_memory: Option<String>,
+    /// `--cpus` limit (for Docker), e.g. "1.5"
+    pub docker_cpus: Option<String>,
+    /// `--shm-size` (for Docker), e.g. "512m"
+    pub docker_shm_size: Option<String>,
+    /// `--network` mode (for Docker): "bridge", "host", or a custom network name
+    pub docker_network_mode: Option<String>,
+    /// Path to the `docker-compose.yml` to drive (for DockerCompose). Falls
+    /// back to `~/.devflow/docker-compose.yml` (generated on install) when
+    /// unset.
+    pub compose_path: Option<PathBuf>,
+    /// Image tag for the generated compose file's `devflow` service (for
+    /// DockerCompose) - only used when the file is generated, which happens
+    /// only while no file exists at the compose path.
+    pub compose_image: Option<String>,
+    /// Restart policy for the generated compose file's `devflow` service
+    /// (for DockerCompose), e.g. "unless-stopped".
+    pub compose_restart_policy: Option<String>,
+    /// Number of pooled TCP connections to the bridge (for Docker,
+    /// DockerCompose, Wsl2, Remote), so several slow commands can run
+    /// concurrently instead of serializing behind one connection. Ignored
+    /// for LocalPython, which only ever has one subprocess to talk to - see
+    /// `effective_pool_size`.
+    #[serde(default = "default_pool_size")]
+    pub pool_size: u32,
+}
+
+/// Default `BackendConfig::pool_size` for TCP-mode backends.
+pub const DEFAULT_POOL_SIZE: u32 = 4;
+
+fn default_pool_size() -> u32 {
+    DEFAULT_POOL_SIZE
 }
 
 impl BackendConfig {
@@ -50,6 +94,7 @@ impl BackendConfig {
             backend_type: BackendType::LocalPython,
             python_path,
             auto_start: true,
+            pool_size: 1,
             ..Default::default()
         }
     }
@@ -62,6 +107,22 @@ impl BackendConfig {
             remote_host: Some("127.0.0.1".to_string()),
             remote_port: Some(9876),
             auto_start: true,
+            pool_size: DEFAULT_POOL_SIZE,
+            ..Default::default()
+        }
+    }
+
+    /// Create a new DockerCompose backend config. `compose_path` points at an
+    /// existing `docker-compose.yml` to drive; pass `None` to generate one
+    /// under `~/.devflow/` on install.
+    pub fn docker_compose(compose_path: Option<PathBuf>) -> Self {
+        Self {
+            backend_type: BackendType::DockerCompose,
+            compose_path,
+            remote_host: Some("127.0.0.1".to_string()),
+            remote_port: Some(9876),
+            auto_start: true,
+            pool_size: DEFAULT_POOL_SIZE,
             ..Default::default()
         }
     }
@@ -74,6 +135,7 @@ impl BackendConfig {
             remote_host: Some("127.0.0.1".to_string()),
             remote_port: Some(9876),
             auto_start: true,
+            pool_size: DEFAULT_POOL_SIZE,
             ..Default::default()
         }
     }
@@ -85,6 +147,7 @@ impl BackendConfig {
             remote_host: Some(host),
             remote_port: Some(port),
             auto_start: false,
+            pool_size: DEFAULT_POOL_SIZE,
             ..Default::default()
         }
     }
@@ -100,21 +163,95 @@ impl BackendConfig {
     pub fn tcp_port(&self) -> u16 {
         self.remote_port.unwrap_or(9876)
     }
+
+    /// Number of pooled TCP connections to use, forcing 1 for LocalPython
+    /// (a single subprocess has nothing to pool) and falling back to 1 if
+    /// `pool_size` was left at its zero-value default.
+    pub fn effective_pool_size(&self) -> u32 {
+        if self.backend_type == BackendType::LocalPython {
+            1
+        } else {
+            self.pool_size.max(1)
+        }
+    }
 }
 
+/// Name of the implicit profile `set_configured` writes to when the caller
+/// has never activated a named one - keeps single-profile users (and the
+/// pre-profiles config format) working without change.
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
 /// Global backend configuration stored at ~/.devflow/backend.json
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct GlobalBackendConfig {
-    /// The default backend configuration
+    /// The active backend configuration, kept in sync with `profiles[active]`
+    /// for readers that predate named profiles.
     pub default_backend: Option<BackendConfig>,
     /// Whether the backend has been configured at least once
     pub configured: bool,
+    /// Named backend profiles, e.g. "work-laptop" -> Docker, "ci" -> Remote.
+    #[serde(default)]
+    pub profiles: HashMap<String, BackendConfig>,
+    /// Name of the profile currently in effect. `None` means
+    /// `DEFAULT_PROFILE_NAME`, which is also what an unmigrated config
+    /// (written before profiles existed) resolves to.
+    #[serde(default)]
+    pub active: Option<String>,
 }
 
 impl GlobalBackendConfig {
     /// Get the path to the global backend config file.
+    ///
+    /// Resolution order: a `--config <path>` launch argument, then the
+    /// `DEVFLOW_BACKEND_CONFIG` environment variable, then the
+    /// `~/.devflow/backend.json` default. An explicit path is resolved
+    /// against the current working directory if relative; if its parent
+    /// directory doesn't exist, the override is ignored and the default
+    /// path is used instead.
     pub fn config_path() -> Option<PathBuf> {
-        dirs::home_dir().map(|home| home.join(".devflow").join("backend.json"))
+        Self::explicit_config_path()
+            .or_else(|| dirs::home_dir().map(|home| home.join(".devflow").join("backend.json")))
+    }
+
+    /// Path the config is actually being loaded from, for display in the UI
+    /// (e.g. `get_active_config_path`).
+    pub fn active_config_path() -> String {
+        Self::config_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "<unresolved>".to_string())
+    }
+
+    /// An explicit override from `--config <path>` or `DEVFLOW_BACKEND_CONFIG`,
+    /// if one was given and its parent directory exists.
+    fn explicit_config_path() -> Option<PathBuf> {
+        let raw = Self::config_path_arg().or_else(|| std::env::var("DEVFLOW_BACKEND_CONFIG").ok())?;
+        let path = PathBuf::from(raw);
+        let path = if path.is_relative() {
+            std::env::current_dir().ok()?.join(path)
+        } else {
+            path
+        };
+
+        match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() && !parent.is_dir() => {
+                log::warn!(
+                    "--config/DEVFLOW_BACKEND_CONFIG points at {} but its parent directory {} doesn't exist; falling back to the default config path",
+                    path.display(),
+                    parent.display()
+                );
+                None
+            }
+            _ => Some(path),
+        }
+    }
+
+    /// Parse a `--config <path>` argument out of this process's own args.
+    fn config_path_arg() -> Option<String> {
+        let args: Vec<String> = std::env::args().collect();
+        args.iter()
+            .position(|arg| arg == "--config")
+            .and_then(|i| args.get(i + 1))
+            .cloned()
     }
 
     /// Load the global backend config from disk.
@@ -142,8 +279,70 @@ impl GlobalBackendConfig {
         Ok(())
     }
 
-    /// Mark as configured with the given backend.
+    /// Name of the profile currently in effect.
+    pub fn active_profile_name(&self) -> String {
+        self.active
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PROFILE_NAME.to_string())
+    }
+
+    /// The resolved config for the active profile, falling back to
+    /// `default_backend` for configs saved before profiles existed.
+    pub fn active_config(&self) -> Option<BackendConfig> {
+        self.profiles
+            .get(&self.active_profile_name())
+            .cloned()
+            .or_else(|| self.default_backend.clone())
+    }
+
+    /// List known profile names alongside their configs.
+    pub fn list_profiles(&self) -> Vec<(String, BackendConfig)> {
+        self.profiles
+            .iter()
+            .map(|(name, config)| (name.clone(), config.clone()))
+            .collect()
+    }
+
+    /// Add or replace a named profile. Does not activate it - call
+    /// `activate_profile` for that.
+    pub fn add_profile(&mut self, name: String, backend: BackendConfig) {
+        self.profiles.insert(name, backend);
+    }
+
+    /// Remove a named profile. Refuses to remove the active profile so
+    /// `active_config` never dangles.
+    pub fn remove_profile(&mut self, name: &str) -> Result<(), String> {
+        if name == self.active_profile_name() {
+            return Err(format!(
+                "Cannot remove \"{}\": it is the active profile",
+                name
+            ));
+        }
+        if self.profiles.remove(name).is_none() {
+            return Err(format!("No profile named \"{}\"", name));
+        }
+        Ok(())
+    }
+
+    /// Switch the active profile, syncing `default_backend` for readers
+    /// that predate named profiles.
+    pub fn activate_profile(&mut self, name: &str) -> Result<(), String> {
+        let backend = self
+            .profiles
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("No profile named \"{}\"", name))?;
+        self.active = Some(name.to_string());
+        self.default_backend = Some(backend);
+        self.configured = true;
+        Ok(())
+    }
+
+    /// Mark as configured with the given backend, saving it under the
+    /// active profile name (`"default"` if none has been activated yet).
     pub fn set_configured(&mut self, backend: BackendConfig) {
+        let name = self.active_profile_name();
+        self.profiles.insert(name, backend.clone());
         self.default_backend = Some(backend);
         self.configured = true;
     }
@@ -152,6 +351,10 @@ impl GlobalBackendConfig {
 /// Project-level backend override (from devflow.yml).
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct ProjectBackendConfig {
+    /// Named global profile to start from instead of the active one, e.g. a
+    /// project that always wants the "ci" profile regardless of what's
+    /// active on the developer's machine.
+    pub profile: Option<String>,
     /// Override backend type
     #[serde(rename = "type")]
     pub backend_type: Option<BackendType>,
@@ -188,6 +391,410 @@ impl ProjectBackendConfig {
 
         result
     }
+
+    /// Resolve against the global profile this project asks for (falling
+    /// back to whichever profile is active) before applying per-project
+    /// overrides.
+    pub fn merge_with_global(&self, global: &GlobalBackendConfig) -> Option<BackendConfig> {
+        let base = match &self.profile {
+            Some(name) => global.profiles.get(name).cloned()?,
+            None => global.active_config()?,
+        };
+        Some(self.merge_with(&base))
+    }
+}
+
+/// Errors resolving a layered `GlobalConfig`. Kept distinct from the
+/// `String`-based errors elsewhere in this file (`save`, `remove_profile`)
+/// because these need to carry a path/field for the CLI-facing error
+/// message, not just a one-line summary.
+#[derive(Error, Debug)]
+pub enum ConfigResolveError {
+    #[error("failed to read {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse {}: {reason}", path.display())]
+    Parse { path: PathBuf, reason: String },
+
+    #[error(
+        "missing required config value \"{field}\": set it in ~/.devflow, a project .devflow.toml, or the {env_var} environment variable"
+    )]
+    MissingField { field: String, env_var: String },
+}
+
+/// Mirrors `GlobalConfig`, but every field is optional so a layer (file or
+/// env) only needs to specify the keys it wants to override.
+#[derive(Clone, Debug, Default, Deserialize)]
+struct PartialGlobalConfig {
+    git: Option<PartialGlobalGitConfig>,
+    defaults: Option<PartialGlobalDefaultsConfig>,
+    infrastructure: Option<PartialGlobalInfrastructureConfig>,
+    setup_completed: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct PartialGlobalGitConfig {
+    user_name: Option<String>,
+    user_email: Option<String>,
+    co_author_enabled: Option<bool>,
+    co_author_name: Option<String>,
+    co_author_email: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct PartialGlobalDefaultsConfig {
+    secrets_provider: Option<String>,
+    network_name: Option<String>,
+    registry: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct PartialGlobalInfrastructureConfig {
+    auto_start: Option<bool>,
+    traefik_http_port: Option<u16>,
+    traefik_https_port: Option<u16>,
+    traefik_dashboard_port: Option<u16>,
+}
+
+/// Resolve `GlobalConfig` in pure Rust, before the Python bridge is running,
+/// by merging layers in increasing precedence:
+///
+/// 1. Built-in defaults
+/// 2. The global config file (`global_config_path()`, `~/.devflow/config.json`
+///    by default) - the same file the bridge-backed `config.get_global`/
+///    `config.set_global` commands read and write once the bridge is up
+/// 3. The project override file (`<project_dir>/.devflow.toml`), if
+///    `project_dir` is given
+/// 4. `DEVFLOW_<SECTION>_<FIELD>` environment variables, e.g.
+///    `DEVFLOW_INFRASTRUCTURE_TRAEFIK_HTTP_PORT`
+///
+/// Later layers override individual fields of earlier ones; unset fields
+/// fall through. Fields with no sensible built-in default (currently just
+/// `defaults.network_name`) must be supplied by one of the later layers, or
+/// resolution fails with `ConfigResolveError::MissingField` naming the key
+/// and the environment variable that would supply it.
+pub fn resolve_global_config(project_dir: Option<&Path>) -> Result<GlobalConfig, ConfigResolveError> {
+    let mut config = builtin_global_config_defaults();
+
+    if let Some(global_path) = global_config_path() {
+        if let Some(partial) = read_json_layer::<PartialGlobalConfig>(&global_path)? {
+            apply_partial_global_config(&mut config, partial);
+        }
+    }
+
+    if let Some(dir) = project_dir {
+        let project_path = dir.join(".devflow.toml");
+        if let Some(partial) = read_toml_layer(&project_path)? {
+            apply_partial_global_config(&mut config, partial);
+        }
+    }
+
+    apply_env_overrides(&mut config);
+    validate_required_fields(&config)?;
+
+    Ok(config)
+}
+
+/// Check fields that have no built-in default and must be supplied by a
+/// later layer, erroring out with the key and suggested override if not.
+fn validate_required_fields(config: &GlobalConfig) -> Result<(), ConfigResolveError> {
+    if config.defaults.network_name.is_empty() {
+        return Err(ConfigResolveError::MissingField {
+            field: "defaults.network_name".to_string(),
+            env_var: "DEVFLOW_DEFAULTS_NETWORK_NAME".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Hardcoded baseline values for every `GlobalConfig` field that has a
+/// sensible default. `defaults.network_name` is deliberately left empty -
+/// every project needs its own Docker network name, so it must come from a
+/// later layer (see `resolve_global_config`'s `MissingField` check).
+fn builtin_global_config_defaults() -> GlobalConfig {
+    GlobalConfig {
+        version: "1".to_string(),
+        git: GlobalGitConfig {
+            user_name: None,
+            user_email: None,
+            co_author_enabled: true,
+            co_author_name: "DevFlow".to_string(),
+            co_author_email: "devflow@users.noreply.github.com".to_string(),
+        },
+        defaults: GlobalDefaultsConfig {
+            secrets_provider: None,
+            network_name: String::new(),
+            registry: None,
+        },
+        infrastructure: GlobalInfrastructureConfig {
+            auto_start: true,
+            traefik_http_port: 80,
+            traefik_https_port: 443,
+            traefik_dashboard_port: 8080,
+        },
+        setup_completed: false,
+    }
+}
+
+fn apply_partial_global_config(config: &mut GlobalConfig, partial: PartialGlobalConfig) {
+    if let Some(git) = partial.git {
+        if let Some(v) = git.user_name {
+            config.git.user_name = Some(v);
+        }
+        if let Some(v) = git.user_email {
+            config.git.user_email = Some(v);
+        }
+        if let Some(v) = git.co_author_enabled {
+            config.git.co_author_enabled = v;
+        }
+        if let Some(v) = git.co_author_name {
+            config.git.co_author_name = v;
+        }
+        if let Some(v) = git.co_author_email {
+            config.git.co_author_email = v;
+        }
+    }
+
+    if let Some(defaults) = partial.defaults {
+        if let Some(v) = defaults.secrets_provider {
+            config.defaults.secrets_provider = Some(v);
+        }
+        if let Some(v) = defaults.network_name {
+            config.defaults.network_name = v;
+        }
+        if let Some(v) = defaults.registry {
+            config.defaults.registry = Some(v);
+        }
+    }
+
+    if let Some(infra) = partial.infrastructure {
+        if let Some(v) = infra.auto_start {
+            config.infrastructure.auto_start = v;
+        }
+        if let Some(v) = infra.traefik_http_port {
+            config.infrastructure.traefik_http_port = v;
+        }
+        if let Some(v) = infra.traefik_https_port {
+            config.infrastructure.traefik_https_port = v;
+        }
+        if let Some(v) = infra.traefik_dashboard_port {
+            config.infrastructure.traefik_dashboard_port = v;
+        }
+    }
+
+    if let Some(v) = partial.setup_completed {
+        config.setup_completed = v;
+    }
+}
+
+/// Apply `DEVFLOW_<SECTION>_<FIELD>` environment variable overrides, the
+/// highest-precedence layer.
+fn apply_env_overrides(config: &mut GlobalConfig) {
+    if let Ok(v) = std::env::var("DEVFLOW_GIT_USER_NAME") {
+        config.git.user_name = Some(v);
+    }
+    if let Ok(v) = std::env::var("DEVFLOW_GIT_USER_EMAIL") {
+        config.git.user_email = Some(v);
+    }
+    if let Some(v) = env_bool("DEVFLOW_GIT_CO_AUTHOR_ENABLED") {
+        config.git.co_author_enabled = v;
+    }
+    if let Ok(v) = std::env::var("DEVFLOW_GIT_CO_AUTHOR_NAME") {
+        config.git.co_author_name = v;
+    }
+    if let Ok(v) = std::env::var("DEVFLOW_GIT_CO_AUTHOR_EMAIL") {
+        config.git.co_author_email = v;
+    }
+
+    if let Ok(v) = std::env::var("DEVFLOW_DEFAULTS_SECRETS_PROVIDER") {
+        config.defaults.secrets_provider = Some(v);
+    }
+    if let Ok(v) = std::env::var("DEVFLOW_DEFAULTS_NETWORK_NAME") {
+        config.defaults.network_name = v;
+    }
+    if let Ok(v) = std::env::var("DEVFLOW_DEFAULTS_REGISTRY") {
+        config.defaults.registry = Some(v);
+    }
+
+    if let Some(v) = env_bool("DEVFLOW_INFRASTRUCTURE_AUTO_START") {
+        config.infrastructure.auto_start = v;
+    }
+    if let Some(v) = env_port("DEVFLOW_INFRASTRUCTURE_TRAEFIK_HTTP_PORT") {
+        config.infrastructure.traefik_http_port = v;
+    }
+    if let Some(v) = env_port("DEVFLOW_INFRASTRUCTURE_TRAEFIK_HTTPS_PORT") {
+        config.infrastructure.traefik_https_port = v;
+    }
+    if let Some(v) = env_port("DEVFLOW_INFRASTRUCTURE_TRAEFIK_DASHBOARD_PORT") {
+        config.infrastructure.traefik_dashboard_port = v;
+    }
+
+    if let Some(v) = env_bool("DEVFLOW_SETUP_COMPLETED") {
+        config.setup_completed = v;
+    }
+}
+
+fn env_bool(name: &str) -> Option<bool> {
+    std::env::var(name).ok().and_then(|v| match v.as_str() {
+        "1" | "true" | "yes" => Some(true),
+        "0" | "false" | "no" => Some(false),
+        _ => None,
+    })
+}
+
+fn env_port(name: &str) -> Option<u16> {
+    std::env::var(name).ok().and_then(|v| v.parse().ok())
+}
+
+/// Path to the global DevFlow config file - the `version`/`git`/`defaults`/
+/// `infrastructure`/`setup_completed` document that the bridge-backed
+/// `config.get_global`/`config.set_global` commands read and write.
+/// Deliberately distinct from `GlobalBackendConfig::config_path()`
+/// (`~/.devflow/backend.json`), which only holds this Rust layer's own
+/// backend-selection/profile settings and has an unrelated shape.
+///
+/// Resolution order: the `DEVFLOW_GLOBAL_CONFIG` environment variable if
+/// set, else `~/.devflow/config.json`.
+fn global_config_path() -> Option<PathBuf> {
+    std::env::var("DEVFLOW_GLOBAL_CONFIG")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".devflow").join("config.json")))
+}
+
+/// Read `path` as a JSON-encoded layer, returning `Ok(None)` if it doesn't
+/// exist (an absent layer just contributes nothing).
+fn read_json_layer<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Option<T>, ConfigResolveError> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path).map_err(|source| ConfigResolveError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| ConfigResolveError::Parse {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+}
+
+/// Read `path` as a `.devflow.toml` layer, returning `Ok(None)` if it doesn't
+/// exist.
+fn read_toml_layer(path: &Path) -> Result<Option<PartialGlobalConfig>, ConfigResolveError> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path).map_err(|source| ConfigResolveError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse_minimal_toml(&content)
+        .map(Some)
+        .map_err(|reason| ConfigResolveError::Parse {
+            path: path.to_path_buf(),
+            reason,
+        })
+}
+
+/// Parses the small, flat subset of TOML this config shape needs:
+/// `[section]` headers one level deep, and `key = value` pairs where value
+/// is a quoted string, an integer, or `true`/`false`. Not a general-purpose
+/// TOML parser - anything outside that shape is reported as a parse error
+/// naming the offending line rather than silently misinterpreted.
+fn parse_minimal_toml(content: &str) -> Result<PartialGlobalConfig, String> {
+    let mut partial = PartialGlobalConfig::default();
+    let mut section = String::new();
+
+    for (line_no, raw_line) in content.lines().enumerate() {
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            section = line.trim_start_matches('[').trim_end_matches(']').trim().to_string();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected `key = value`, got `{}`", line_no + 1, raw_line))?;
+
+        set_partial_field(&mut partial, &section, key.trim(), value.trim())
+            .map_err(|e| format!("line {}: {}", line_no + 1, e))?;
+    }
+
+    Ok(partial)
+}
+
+fn set_partial_field(
+    partial: &mut PartialGlobalConfig,
+    section: &str,
+    key: &str,
+    raw_value: &str,
+) -> Result<(), String> {
+    match section {
+        "git" => {
+            let git = partial.git.get_or_insert_with(Default::default);
+            match key {
+                "user_name" => git.user_name = Some(parse_toml_string(raw_value)?),
+                "user_email" => git.user_email = Some(parse_toml_string(raw_value)?),
+                "co_author_enabled" => git.co_author_enabled = Some(parse_toml_bool(raw_value)?),
+                "co_author_name" => git.co_author_name = Some(parse_toml_string(raw_value)?),
+                "co_author_email" => git.co_author_email = Some(parse_toml_string(raw_value)?),
+                other => return Err(format!("unknown key \"git.{}\"", other)),
+            }
+        }
+        "defaults" => {
+            let defaults = partial.defaults.get_or_insert_with(Default::default);
+            match key {
+                "secrets_provider" => defaults.secrets_provider = Some(parse_toml_string(raw_value)?),
+                "network_name" => defaults.network_name = Some(parse_toml_string(raw_value)?),
+                "registry" => defaults.registry = Some(parse_toml_string(raw_value)?),
+                other => return Err(format!("unknown key \"defaults.{}\"", other)),
+            }
+        }
+        "infrastructure" => {
+            let infra = partial.infrastructure.get_or_insert_with(Default::default);
+            match key {
+                "auto_start" => infra.auto_start = Some(parse_toml_bool(raw_value)?),
+                "traefik_http_port" => infra.traefik_http_port = Some(parse_toml_port(raw_value)?),
+                "traefik_https_port" => infra.traefik_https_port = Some(parse_toml_port(raw_value)?),
+                "traefik_dashboard_port" => infra.traefik_dashboard_port = Some(parse_toml_port(raw_value)?),
+                other => return Err(format!("unknown key \"infrastructure.{}\"", other)),
+            }
+        }
+        "" if key == "setup_completed" => partial.setup_completed = Some(parse_toml_bool(raw_value)?),
+        other => return Err(format!("unknown section \"[{}]\"", other)),
+    }
+    Ok(())
+}
+
+fn parse_toml_string(raw: &str) -> Result<String, String> {
+    if raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"') {
+        Ok(raw[1..raw.len() - 1].to_string())
+    } else {
+        Err(format!("expected a quoted string, got `{}`", raw))
+    }
+}
+
+fn parse_toml_bool(raw: &str) -> Result<bool, String> {
+    match raw {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        other => Err(format!("expected true/false, got `{}`", other)),
+    }
+}
+
+fn parse_toml_port(raw: &str) -> Result<u16, String> {
+    raw.parse::<u16>()
+        .map_err(|_| format!("expected a port number, got `{}`", raw))
 }
 
 #[cfg(test)]
@@ -235,6 +842,87 @@ mod tests {
         assert_eq!(merged.tcp_port(), 9999);
     }
 
+    #[test]
+    fn test_effective_pool_size() {
+        assert_eq!(
+            BackendConfig::local_python(None).effective_pool_size(),
+            1
+        );
+        assert_eq!(
+            BackendConfig::docker(None).effective_pool_size(),
+            DEFAULT_POOL_SIZE
+        );
+
+        let mut zeroed = BackendConfig::docker(None);
+        zeroed.pool_size = 0;
+        assert_eq!(zeroed.effective_pool_size(), 1);
+    }
+
+    #[test]
+    fn test_profile_activation() {
+        let mut global = GlobalBackendConfig::default();
+        global.add_profile("work".to_string(), BackendConfig::docker(None));
+        global.add_profile(
+            "ci".to_string(),
+            BackendConfig::remote("ci.internal".to_string(), 9876),
+        );
+
+        assert_eq!(global.active_profile_name(), DEFAULT_PROFILE_NAME);
+        assert!(global.active_config().is_none());
+
+        global.activate_profile("work").unwrap();
+        assert_eq!(global.active_profile_name(), "work");
+        assert_eq!(
+            global.active_config().unwrap().backend_type,
+            BackendType::Docker
+        );
+
+        assert!(global.activate_profile("missing").is_err());
+        assert!(global.remove_profile("work").is_err(), "can't remove active profile");
+        global.remove_profile("ci").unwrap();
+        assert!(global.profiles.get("ci").is_none());
+    }
+
+    #[test]
+    fn test_set_configured_writes_active_profile() {
+        let mut global = GlobalBackendConfig::default();
+        global.set_configured(BackendConfig::local_python(None));
+        assert_eq!(
+            global.profiles.get(DEFAULT_PROFILE_NAME).unwrap().backend_type,
+            BackendType::LocalPython
+        );
+
+        global.add_profile("work".to_string(), BackendConfig::docker(None));
+        global.activate_profile("work").unwrap();
+        global.set_configured(BackendConfig::wsl2(None));
+        assert_eq!(
+            global.profiles.get("work").unwrap().backend_type,
+            BackendType::Wsl2
+        );
+    }
+
+    #[test]
+    fn test_project_merge_with_named_profile() {
+        let mut global = GlobalBackendConfig::default();
+        global.add_profile("ci".to_string(), BackendConfig::docker(None));
+
+        let project = ProjectBackendConfig {
+            profile: Some("ci".to_string()),
+            port: Some(9999),
+            ..Default::default()
+        };
+
+        let merged = project.merge_with_global(&global).unwrap();
+        assert_eq!(merged.backend_type, BackendType::Docker);
+        assert_eq!(merged.tcp_port(), 9999);
+
+        let no_such_profile = ProjectBackendConfig {
+            profile: Some("missing".to_string()),
+            ..Default::default()
+        };
+        assert!(no_such_profile.merge_with_global(&global).is_none());
+    }
+
     #[test]
     fn test_serialization() {
         let config = BackendConfig::local_python(Some(PathBuf::from("/usr/bin/python3")));
@@ -243,4 +931,152 @@ mod tests {
         assert_eq!(parsed.backend_type, BackendType::LocalPython);
         assert_eq!(parsed.python_path, Some(PathBuf::from("/usr/bin/python3")));
     }
+
+    #[test]
+    fn test_config_path_honors_env_var_override() {
+        // "/tmp" always exists, so this exercises the override without
+        // depending on a directory this test would need to create.
+        std::env::set_var("DEVFLOW_BACKEND_CONFIG", "/tmp/devflow-config-test-backend.json");
+        assert_eq!(
+            GlobalBackendConfig::config_path(),
+            Some(PathBuf::from("/tmp/devflow-config-test-backend.json"))
+        );
+        std::env::remove_var("DEVFLOW_BACKEND_CONFIG");
+    }
+
+    #[test]
+    fn test_config_path_ignores_override_with_missing_parent_dir() {
+        std::env::set_var(
+            "DEVFLOW_BACKEND_CONFIG",
+            "/nonexistent/devflow-config-test-root/backend.json",
+        );
+        let resolved = GlobalBackendConfig::config_path();
+        std::env::remove_var("DEVFLOW_BACKEND_CONFIG");
+        assert_ne!(
+            resolved,
+            Some(PathBuf::from("/nonexistent/devflow-config-test-root/backend.json"))
+        );
+    }
+
+    #[test]
+    fn test_parse_minimal_toml() {
+        let toml = "[git]\nuser_name = \"Ada\"\nco_author_enabled = false\n\n[infrastructure]\ntraefik_http_port = 8080\n";
+        let partial = parse_minimal_toml(toml).unwrap();
+        assert_eq!(partial.git.unwrap().user_name, Some("Ada".to_string()));
+        assert_eq!(
+            partial.infrastructure.unwrap().traefik_http_port,
+            Some(8080)
+        );
+    }
+
+    #[test]
+    fn test_parse_minimal_toml_rejects_unknown_key() {
+        let err = parse_minimal_toml("[git]\nbogus = \"x\"\n").unwrap_err();
+        assert!(err.contains("bogus"));
+    }
+
+    #[test]
+    fn test_builtin_defaults_require_network_name() {
+        let defaults = builtin_global_config_defaults();
+        assert!(defaults.defaults.network_name.is_empty());
+        assert_eq!(defaults.infrastructure.traefik_http_port, 80);
+    }
+
+    #[test]
+    fn test_apply_env_overrides() {
+        std::env::set_var("DEVFLOW_INFRASTRUCTURE_TRAEFIK_HTTP_PORT", "9999");
+        std::env::set_var("DEVFLOW_DEFAULTS_NETWORK_NAME", "devflow-test-net");
+        let mut config = builtin_global_config_defaults();
+        apply_env_overrides(&mut config);
+        std::env::remove_var("DEVFLOW_INFRASTRUCTURE_TRAEFIK_HTTP_PORT");
+        std::env::remove_var("DEVFLOW_DEFAULTS_NETWORK_NAME");
+
+        assert_eq!(config.infrastructure.traefik_http_port, 9999);
+        assert_eq!(config.defaults.network_name, "devflow-test-net");
+    }
+
+    #[test]
+    fn test_validate_required_fields_rejects_empty_network_name() {
+        let config = builtin_global_config_defaults();
+        match validate_required_fields(&config) {
+            Err(ConfigResolveError::MissingField { field, env_var }) => {
+                assert_eq!(field, "defaults.network_name");
+                assert_eq!(env_var, "DEVFLOW_DEFAULTS_NETWORK_NAME");
+            }
+            other => panic!("expected MissingField, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_global_config_reads_project_toml_layer() {
+        let dir = std::env::temp_dir().join(format!(
+            "devflow-config-test-project-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(".devflow.toml"),
+            "[defaults]\nnetwork_name = \"devflow-net\"\n",
+        )
+        .unwrap();
+
+        let config = resolve_global_config(Some(&dir)).unwrap();
+        assert_eq!(config.defaults.network_name, "devflow-net");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_global_config_reads_global_json_layer() {
+        let dir = std::env::temp_dir().join(format!(
+            "devflow-config-test-global-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let global_path = dir.join("config.json");
+        fs::write(
+            &global_path,
+            r#"{"defaults": {"network_name": "devflow-global-net"}, "infrastructure": {"traefik_http_port": 8888}}"#,
+        )
+        .unwrap();
+
+        std::env::set_var("DEVFLOW_GLOBAL_CONFIG", &global_path);
+        let config = resolve_global_config(None);
+        std::env::remove_var("DEVFLOW_GLOBAL_CONFIG");
+        fs::remove_dir_all(&dir).unwrap();
+
+        let config = config.unwrap();
+        assert_eq!(config.defaults.network_name, "devflow-global-net");
+        assert_eq!(config.infrastructure.traefik_http_port, 8888);
+    }
+
+    #[test]
+    fn test_resolve_global_config_ignores_backend_profile_schema() {
+        // The global layer's file is `GlobalConfig`-shaped (defaults/
+        // infrastructure/git), not `GlobalBackendConfig`'s disjoint
+        // profiles/default_backend/configured schema - content in that
+        // shape contributes nothing and must not spuriously satisfy
+        // `network_name`.
+        let dir = std::env::temp_dir().join(format!(
+            "devflow-config-test-backend-shape-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let global_path = dir.join("config.json");
+        fs::write(
+            &global_path,
+            r#"{"default_backend": null, "configured": false, "profiles": {}, "active": null}"#,
+        )
+        .unwrap();
+
+        std::env::set_var("DEVFLOW_GLOBAL_CONFIG", &global_path);
+        let result = resolve_global_config(None);
+        std::env::remove_var("DEVFLOW_GLOBAL_CONFIG");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(
+            result,
+            Err(ConfigResolveError::MissingField { .. })
+        ));
+    }
 }
@@ -1,6 +1,7 @@
 //! Prerequisite detection - runs in pure Rust without Python bridge.
 
 use serde::{Deserialize, Serialize};
+use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
@@ -13,6 +14,15 @@ pub struct PrerequisiteStatus {
     pub python_version: Option<String>,
     /// Path to Python executable
     pub python_path: Option<PathBuf>,
+    /// Rich interpreter details from actually running Python, rather than
+    /// parsing its `--version` string. `None` if the probe script failed
+    /// (unusual, but not fatal - `python_version` still carries the
+    /// `--version`-derived fallback).
+    pub interpreter: Option<InterpreterInfo>,
+    /// Verdict from `check_python_compatibility`, so the UI can report e.g.
+    /// "Python 3.5 found but 3.10+ required" instead of just
+    /// `python_available: true`. `None` alongside `interpreter: None`.
+    pub python_compatibility: Option<PythonCompatibility>,
     /// Whether devflow package is installed
     pub devflow_installed: bool,
     /// DevFlow package version
@@ -27,6 +37,14 @@ pub struct PrerequisiteStatus {
     pub wsl_available: bool,
     /// List of available WSL distributions
     pub wsl_distros: Vec<String>,
+    /// Whether DevFlow itself is running inside a container (dev container,
+    /// CI runner), in which case Docker/WSL backends see container-internal
+    /// paths and container IDs rather than the outer host's.
+    pub running_in_container: bool,
+    /// Linux distribution identity, so the UI can tailor install guidance
+    /// (apt vs dnf vs pacman) instead of a generic "install Python" prompt.
+    /// `None` on non-Linux hosts.
+    pub distro: Option<DistroInfo>,
 }
 
 /// Detailed status of a WSL distribution.
@@ -46,6 +64,360 @@ pub struct WslDistroStatus {
     pub devflow_installed: bool,
     /// DevFlow package version in this distro
     pub devflow_version: Option<String>,
+    /// This distro's identity, read from its own `/etc/os-release`.
+    pub distro: Option<DistroInfo>,
+}
+
+/// Linux distribution identity, parsed from `/etc/os-release`'s `ID`,
+/// `VERSION_ID`, and `PRETTY_NAME` keys.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DistroInfo {
+    pub id: String,
+    pub version: String,
+    pub name: String,
+    pub package_manager: Option<super::installer::WslPackageManager>,
+}
+
+/// Map a distro's `ID`/`ID_LIKE` family to the package manager it ships. The
+/// single source of truth for this ladder - `installer::detect_wsl_package_manager`
+/// and `installer::detect_linux_package_manager` both call this rather than
+/// keeping their own copy, so a host is never classified differently
+/// depending on which call site asked.
+///
+/// RHEL/CentOS moved from yum to dnf at version 8; Fedora made the same
+/// switch much earlier, at version 22 - so `id` (not just the combined
+/// `ID`/`ID_LIKE` family) decides which cutoff applies. This checks
+/// `VERSION_ID` directly rather than probing for a live `dnf` binary (which
+/// would need an extra subprocess - or, for a WSL distro, an extra `wsl`
+/// round trip).
+pub(crate) fn classify_package_manager(
+    id: &str,
+    id_like: &str,
+    version: &str,
+) -> Option<super::installer::WslPackageManager> {
+    use super::installer::WslPackageManager;
+
+    let id_lower = id.to_lowercase();
+    let family = format!("{} {}", id, id_like).to_lowercase();
+    let major_version: u32 = version.split('.').next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let yum_until = |dnf_since: u32| {
+        Some(if major_version > 0 && major_version < dnf_since {
+            WslPackageManager::Yum
+        } else {
+            WslPackageManager::Dnf
+        })
+    };
+
+    if family.contains("debian") || family.contains("ubuntu") {
+        Some(WslPackageManager::Apt)
+    } else if family.contains("arch") {
+        Some(WslPackageManager::Pacman)
+    } else if family.contains("alpine") {
+        Some(WslPackageManager::Apk)
+    } else if family.contains("suse") {
+        Some(WslPackageManager::Zypper)
+    } else if id_lower == "fedora" {
+        // Checked against `id` alone, not the combined family string: RHEL
+        // and CentOS both commonly report `ID_LIKE="... fedora"` too (see
+        // the RHEL 8 test fixture below), and they didn't switch to dnf
+        // until 8, six years after Fedora did.
+        yum_until(22)
+    } else if family.contains("rhel") || family.contains("centos") || family.contains("fedora") {
+        yum_until(8)
+    } else {
+        None
+    }
+}
+
+/// Parse `/etc/os-release` content - however it was read, locally or via
+/// `wsl -d <distro> -- cat` - into a `DistroInfo`. `None` if `ID` is missing,
+/// which means this isn't actually an os-release file.
+fn parse_os_release(content: &str) -> Option<DistroInfo> {
+    let mut id = String::new();
+    let mut id_like = String::new();
+    let mut version = String::new();
+    let mut name = String::new();
+
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key {
+            "ID" => id = value,
+            "ID_LIKE" => id_like = value,
+            "VERSION_ID" => version = value,
+            "PRETTY_NAME" => name = value,
+            _ => {}
+        }
+    }
+
+    if id.is_empty() {
+        return None;
+    }
+
+    let package_manager = classify_package_manager(&id, &id_like, &version);
+
+    Some(DistroInfo {
+        id,
+        version,
+        name,
+        package_manager,
+    })
+}
+
+/// Detect the local Linux distribution from `/etc/os-release`. `None` on
+/// non-Linux hosts, or if the file is missing/unparsable.
+#[cfg(target_os = "linux")]
+pub fn detect_distro() -> Option<DistroInfo> {
+    let content = fs::read_to_string("/etc/os-release").ok()?;
+    parse_os_release(&content)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn detect_distro() -> Option<DistroInfo> {
+    None
+}
+
+/// Detect a WSL distro's Linux distribution by catting its `/etc/os-release`.
+#[cfg(windows)]
+pub fn detect_distro_wsl(distro: &str) -> Option<DistroInfo> {
+    let output = Command::new("wsl")
+        .args(["-d", distro, "--", "cat", "/etc/os-release"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_os_release(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(not(windows))]
+pub fn detect_distro_wsl(_distro: &str) -> Option<DistroInfo> {
+    None
+}
+
+/// Python interpreter implementation, as reported by `sys.implementation.name`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PythonImplementation {
+    CPython,
+    PyPy,
+    Other(String),
+}
+
+/// A structured, trustworthy description of a Python interpreter, populated
+/// by actually running it rather than parsing a `--version` string.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterpreterInfo {
+    pub implementation: PythonImplementation,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub executable: PathBuf,
+    /// `sys.prefix` - the active environment's prefix, which differs from
+    /// `base_prefix` when the interpreter is running inside a venv.
+    pub prefix: String,
+    /// `sys.base_prefix` - the underlying system installation's prefix.
+    pub base_prefix: String,
+    /// `sysconfig.get_config_var("ABIFLAGS")`, e.g. "m" on old debug/pymalloc
+    /// builds or "" on modern CPython.
+    pub abiflags: String,
+    /// `struct.calcsize("P") * 8` - 64 or 32.
+    pub pointer_width: u32,
+    /// Whether this interpreter was provisioned by devflow itself (via
+    /// `python_runtime::bootstrap_python`) rather than discovered on the
+    /// host, so the UI can tell the two apart.
+    #[serde(default)]
+    pub managed: bool,
+}
+
+impl InterpreterInfo {
+    /// Whether this interpreter's `(major, minor)` is at least `(min_major, min_minor)`.
+    pub fn meets_minimum(&self, min_major: u32, min_minor: u32) -> bool {
+        (self.major, self.minor) >= (min_major, min_minor)
+    }
+
+    /// Whether this is a 64-bit build of the interpreter.
+    pub fn is_64bit(&self) -> bool {
+        self.pointer_width == 64
+    }
+}
+
+/// A Python version as `(major, minor)`, ignoring patch - compatibility
+/// decisions only care about the language/ABI generation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PythonVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+/// Oldest Python 3 minor version devflow's native dependencies support.
+pub const PY3_MIN_MINOR: u8 = 10;
+/// Newest Python 3 minor version devflow has been validated against. Newer
+/// interpreters aren't rejected by anything else in this codebase, but
+/// flagging them here gives a user hitting a real incompatibility somewhere
+/// to look first.
+pub const PY3_MAX_MINOR: u8 = 13;
+
+/// Result of checking a probed interpreter against devflow's supported
+/// Python version/bitness range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PythonCompatibility {
+    Supported,
+    TooOld,
+    TooNew,
+    WrongBitness,
+}
+
+/// Check `info` against devflow's supported Python version/bitness range.
+///
+/// Bitness is checked first: mirroring the way build tooling skips
+/// interpreters whose pointer width doesn't match the target, a 32-bit
+/// interpreter on a 64-bit host can't load devflow's native extensions
+/// regardless of its Python version.
+pub fn check_python_compatibility(info: &InterpreterInfo) -> PythonCompatibility {
+    let host_pointer_width: u32 = if cfg!(target_pointer_width = "64") { 64 } else { 32 };
+    if info.pointer_width != host_pointer_width {
+        return PythonCompatibility::WrongBitness;
+    }
+
+    if info.major < 3 {
+        return PythonCompatibility::TooOld;
+    }
+
+    let version = PythonVersion {
+        major: info.major as u8,
+        minor: info.minor as u8,
+    };
+    if version
+        < (PythonVersion {
+            major: 3,
+            minor: PY3_MIN_MINOR,
+        })
+    {
+        return PythonCompatibility::TooOld;
+    }
+    if info.major > 3
+        || version
+            > (PythonVersion {
+                major: 3,
+                minor: PY3_MAX_MINOR,
+            })
+    {
+        return PythonCompatibility::TooNew;
+    }
+
+    PythonCompatibility::Supported
+}
+
+/// A script that prints a single JSON object with the facts we need, so the
+/// output can be parsed without guessing at `--version`'s formatting or
+/// relying on a fixed line order.
+const INTERPRETER_PROBE_SCRIPT: &str = r#"import json, struct, sys, sysconfig
+print(json.dumps({
+    "implementation": sys.implementation.name,
+    "major": sys.version_info.major,
+    "minor": sys.version_info.minor,
+    "micro": sys.version_info.micro,
+    "executable": sys.executable,
+    "prefix": sys.prefix,
+    "base_prefix": sys.base_prefix,
+    "abiflags": sysconfig.get_config_var("ABIFLAGS") or "",
+    "pointer_width": struct.calcsize("P") * 8,
+}))
+"#;
+
+/// Raw shape of `INTERPRETER_PROBE_SCRIPT`'s JSON output.
+#[derive(Deserialize)]
+struct InterpreterProbeJson {
+    implementation: String,
+    major: u32,
+    minor: u32,
+    micro: u32,
+    prefix: String,
+    base_prefix: String,
+    abiflags: String,
+    pointer_width: u32,
+}
+
+/// Parse the probe script's stdout into an `InterpreterInfo`. `executable` is
+/// taken from the path/command we ran rather than the script's own
+/// `sys.executable`, since on Windows the WSL caller passes a distro-relative
+/// command that `sys.executable` wouldn't resolve back to from the host.
+fn parse_interpreter_probe(executable: PathBuf, stdout: &str) -> Option<InterpreterInfo> {
+    let parsed: InterpreterProbeJson = serde_json::from_str(stdout.trim()).ok()?;
+
+    let implementation = match parsed.implementation.to_lowercase().as_str() {
+        "cpython" => PythonImplementation::CPython,
+        "pypy" => PythonImplementation::PyPy,
+        other => PythonImplementation::Other(other.to_string()),
+    };
+
+    Some(InterpreterInfo {
+        implementation,
+        major: parsed.major,
+        minor: parsed.minor,
+        patch: parsed.micro,
+        executable,
+        prefix: parsed.prefix,
+        base_prefix: parsed.base_prefix,
+        abiflags: parsed.abiflags,
+        pointer_width: parsed.pointer_width,
+        managed: false,
+    })
+}
+
+/// Probe a local interpreter by actually running it, instead of parsing its
+/// `--version` string.
+pub fn probe_interpreter(python: &std::path::Path) -> Option<InterpreterInfo> {
+    probe_interpreter_with_args(python, &[])
+}
+
+/// Probe an interpreter that needs extra arguments before `-c` - namely the
+/// Windows `py` launcher's `-3.x` version selector.
+fn probe_interpreter_with_args(python: &std::path::Path, extra_args: &[&str]) -> Option<InterpreterInfo> {
+    let output = Command::new(python)
+        .args(extra_args)
+        .args(["-c", INTERPRETER_PROBE_SCRIPT])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_interpreter_probe(
+        python.to_path_buf(),
+        &String::from_utf8_lossy(&output.stdout),
+    )
+}
+
+/// Probe a WSL distro's `python3` by running the same probe script inside it.
+#[cfg(windows)]
+pub fn probe_interpreter_wsl(distro: &str) -> Option<InterpreterInfo> {
+    // Single-quoted so the script's own double quotes (JSON keys) pass
+    // through the `bash -c` wrapper untouched.
+    let cmd = format!(
+        "python3 -c '{}' 2>/dev/null || python -c '{}' 2>/dev/null",
+        INTERPRETER_PROBE_SCRIPT, INTERPRETER_PROBE_SCRIPT
+    );
+
+    let output = Command::new("wsl")
+        .args(["-d", distro, "--", "bash", "-c", &cmd])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_interpreter_probe(
+        PathBuf::from("python3"),
+        &String::from_utf8_lossy(&output.stdout),
+    )
+}
+
+#[cfg(not(windows))]
+pub fn probe_interpreter_wsl(_distro: &str) -> Option<InterpreterInfo> {
+    None
 }
 
 /// Detect Python installation.
@@ -80,6 +452,95 @@ pub fn detect_python() -> (bool, Option<String>, Option<PathBuf>) {
     (false, None, None)
 }
 
+/// Candidate commands/paths to probe for `discover_interpreters`: versioned
+/// binaries on PATH (newest first, so a user skimming the list sees the
+/// most capable interpreter up top), the bare `python`/`python3`, pyenv
+/// shims under `~/.pyenv/versions/*/bin/python`, and any active/known
+/// virtualenv (`VIRTUAL_ENV`, `CONDA_PREFIX`).
+fn discover_interpreter_candidates() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    for minor in (8..=13).rev() {
+        candidates.push(PathBuf::from(format!("python3.{}", minor)));
+    }
+    candidates.push(PathBuf::from("python3"));
+    candidates.push(PathBuf::from("python"));
+
+    if cfg!(windows) {
+        for minor in (8..=13).rev() {
+            candidates.push(PathBuf::from(format!("py -3.{}", minor)));
+        }
+    }
+
+    if let Some(home) = dirs::home_dir() {
+        let pyenv_versions = home.join(".pyenv").join("versions");
+        if let Ok(entries) = fs::read_dir(&pyenv_versions) {
+            for entry in entries.flatten() {
+                let python = if cfg!(windows) {
+                    entry.path().join("python.exe")
+                } else {
+                    entry.path().join("bin").join("python")
+                };
+                if python.exists() {
+                    candidates.push(python);
+                }
+            }
+        }
+    }
+
+    for env_var in ["VIRTUAL_ENV", "CONDA_PREFIX"] {
+        if let Ok(prefix) = std::env::var(env_var) {
+            let python = if cfg!(windows) {
+                PathBuf::from(&prefix).join("Scripts").join("python.exe")
+            } else {
+                PathBuf::from(&prefix).join("bin").join("python")
+            };
+            if python.exists() {
+                candidates.push(python);
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Probe every interpreter reachable from PATH, pyenv, and active
+/// virtualenvs, instead of stopping at the first match like `detect_python`
+/// does. Lets a user with multiple Pythons installed pick which one devflow
+/// installs into.
+///
+/// Deduplicates by canonicalized `executable` path - a `python3.11` on PATH
+/// and `~/.pyenv/versions/3.11.6/bin/python` can resolve to the same
+/// interpreter, and should only appear once.
+pub fn discover_interpreters() -> Vec<InterpreterInfo> {
+    let mut seen = std::collections::HashSet::new();
+    let mut interpreters = Vec::new();
+
+    for candidate in discover_interpreter_candidates() {
+        // Windows `py -3.x` launcher entries are two words; everything else
+        // is a single command/path runnable directly.
+        let candidate_str = candidate.to_string_lossy().to_string();
+        let mut parts = candidate_str.split_whitespace();
+        let Some(program) = parts.next() else { continue };
+        let extra_args: Vec<&str> = parts.collect();
+
+        let is_path = program.contains(std::path::MAIN_SEPARATOR) || PathBuf::from(program).is_absolute();
+        let Some(resolved) = (if is_path { Some(PathBuf::from(program)) } else { find_executable(program) }) else {
+            continue;
+        };
+
+        let info = probe_interpreter_with_args(&resolved, &extra_args);
+        let Some(info) = info else { continue };
+
+        let key = fs::canonicalize(&info.executable).unwrap_or_else(|_| info.executable.clone());
+        if seen.insert(key) {
+            interpreters.push(info);
+        }
+    }
+
+    interpreters
+}
+
 /// Check if devflow package is installed.
 ///
 /// Runs `python -c "import devflow; print(devflow.__version__)"`.
@@ -330,6 +791,7 @@ pub fn check_wsl_distro_status(distro: &str) -> WslDistroStatus {
     } else {
         (false, None)
     };
+    let distro_info = if is_running { detect_distro_wsl(distro) } else { None };
 
     WslDistroStatus {
         name: distro.to_string(),
@@ -339,6 +801,7 @@ pub fn check_wsl_distro_status(distro: &str) -> WslDistroStatus {
         python_version,
         devflow_installed,
         devflow_version,
+        distro: distro_info,
     }
 }
 
@@ -418,6 +881,14 @@ pub fn check_devflow_in_wsl(_distro: &str) -> (bool, Option<String>) {
 /// Detect all prerequisites at once.
 pub fn detect_all_prerequisites() -> PrerequisiteStatus {
     let (python_available, python_version, python_path) = detect_python();
+    let interpreter = python_path.as_ref().and_then(|p| probe_interpreter(p));
+    // Prefer the probed version over the `--version`-string one: it can't be
+    // thrown off by distro patch suffixes or pre-release tags.
+    let python_version = interpreter
+        .as_ref()
+        .map(|info| format!("{}.{}.{}", info.major, info.minor, info.patch))
+        .or(python_version);
+    let python_compatibility = interpreter.as_ref().map(check_python_compatibility);
     let (devflow_installed, devflow_version) = if python_available {
         check_devflow_installed(python_path.as_ref())
     } else {
@@ -430,6 +901,8 @@ pub fn detect_all_prerequisites() -> PrerequisiteStatus {
         python_available,
         python_version,
         python_path,
+        interpreter,
+        python_compatibility,
         devflow_installed,
         devflow_version,
         docker_available,
@@ -437,9 +910,95 @@ pub fn detect_all_prerequisites() -> PrerequisiteStatus {
         docker_version,
         wsl_available,
         wsl_distros,
+        running_in_container: detect_in_container(),
+        distro: detect_distro(),
+    }
+}
+
+/// Whether the current process is itself running inside a container (a dev
+/// container, CI runner, etc). Docker/WSL backends assume they can see the
+/// host's filesystem and name sibling containers by ID directly; neither
+/// holds once DevFlow is nested one level down.
+///
+/// Checked two ways, either of which is sufficient:
+/// - `/.dockerenv` exists, which the Docker runtime creates in every
+///   container's root filesystem.
+/// - `/proc/1/cgroup` mentions a `docker`/`containerd` controller path,
+///   which container runtimes place PID 1 under but a bare-metal/VM init
+///   process never is.
+#[cfg(target_os = "linux")]
+pub fn detect_in_container() -> bool {
+    if std::path::Path::new("/.dockerenv").exists() {
+        return true;
+    }
+
+    std::fs::read_to_string("/proc/1/cgroup")
+        .map(|contents| contents.lines().any(|line| line.contains("docker") || line.contains("containerd")))
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn detect_in_container() -> bool {
+    // Containerized DevFlow only arises via a Linux container runtime, even
+    // when the host launching it is Windows/macOS.
+    false
+}
+
+/// Rewrite a path as seen from inside DevFlow's own container to the
+/// corresponding path on the outer host, so volume-bound project paths
+/// resolve correctly when DevFlow spawns Docker/WSL backends from a dev
+/// container. Returns the path unchanged if we're not containerized, the
+/// container's own id/hostname can't be resolved, or no mount covers it.
+pub fn translate_host_path(path: &std::path::Path) -> std::path::PathBuf {
+    if !detect_in_container() {
+        return path.to_path_buf();
+    }
+
+    let Ok(hostname) = std::fs::read_to_string("/etc/hostname") else {
+        return path.to_path_buf();
+    };
+    let container_id = hostname.trim();
+    if container_id.is_empty() {
+        return path.to_path_buf();
+    }
+
+    let output = Command::new("docker")
+        .args(["inspect", "--format", "{{json .Mounts}}", container_id])
+        .output();
+    let Ok(output) = output else { return path.to_path_buf() };
+    if !output.status.success() {
+        return path.to_path_buf();
+    }
+
+    let Ok(mounts) = serde_json::from_slice::<Vec<DockerMount>>(&output.stdout) else {
+        return path.to_path_buf();
+    };
+
+    // Prefer the most specific (longest) destination prefix that covers the
+    // path, so a mount at /workspace/app wins over one at /workspace.
+    let best = mounts
+        .iter()
+        .filter(|m| path.starts_with(&m.destination))
+        .max_by_key(|m| m.destination.as_os_str().len());
+
+    match best {
+        Some(mount) => match path.strip_prefix(&mount.destination) {
+            Ok(relative) => mount.source.join(relative),
+            Err(_) => path.to_path_buf(),
+        },
+        None => path.to_path_buf(),
     }
 }
 
+/// One entry of `docker inspect --format '{{json .Mounts}}'`.
+#[derive(Debug, Deserialize)]
+struct DockerMount {
+    #[serde(rename = "Source")]
+    source: PathBuf,
+    #[serde(rename = "Destination")]
+    destination: PathBuf,
+}
+
 /// Find the path to an executable.
 fn find_executable(name: &str) -> Option<PathBuf> {
     #[cfg(windows)]
@@ -539,6 +1098,137 @@ pub fn test_devflow_connection(host: &str, port: u16) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_interpreter_probe() {
+        let stdout = r#"{"implementation":"cpython","major":3,"minor":11,"micro":5,"executable":"/usr/bin/python3","prefix":"/usr","base_prefix":"/usr","abiflags":"","pointer_width":64}"#;
+        let info = parse_interpreter_probe(PathBuf::from("/usr/bin/python3"), stdout).unwrap();
+        assert_eq!(info.implementation, PythonImplementation::CPython);
+        assert_eq!((info.major, info.minor, info.patch), (3, 11, 5));
+        assert_eq!(info.prefix, "/usr");
+        assert_eq!(info.base_prefix, "/usr");
+        assert!(info.is_64bit());
+        assert!(info.meets_minimum(3, 10));
+        assert!(!info.meets_minimum(3, 12));
+        assert!(!info.managed);
+    }
+
+    #[test]
+    fn test_parse_interpreter_probe_pypy() {
+        let stdout = r#"{"implementation":"pypy","major":3,"minor":9,"micro":0,"executable":"pypy3","prefix":"/opt/pypy","base_prefix":"/opt/pypy","abiflags":"","pointer_width":32}"#;
+        let info = parse_interpreter_probe(PathBuf::from("pypy3"), stdout).unwrap();
+        assert_eq!(info.implementation, PythonImplementation::PyPy);
+        assert!(!info.is_64bit());
+    }
+
+    #[test]
+    fn test_parse_interpreter_probe_truncated_output() {
+        assert!(parse_interpreter_probe(PathBuf::from("python3"), "not json").is_none());
+        assert!(parse_interpreter_probe(PathBuf::from("python3"), r#"{"implementation":"cpython"}"#).is_none());
+    }
+
+    fn make_interpreter_info(major: u32, minor: u32, pointer_width: u32) -> InterpreterInfo {
+        InterpreterInfo {
+            implementation: PythonImplementation::CPython,
+            major,
+            minor,
+            patch: 0,
+            executable: PathBuf::from("python3"),
+            prefix: "/usr".to_string(),
+            base_prefix: "/usr".to_string(),
+            abiflags: String::new(),
+            pointer_width,
+            managed: false,
+        }
+    }
+
+    #[test]
+    fn test_check_python_compatibility_supported() {
+        let host_bits = if cfg!(target_pointer_width = "64") { 64 } else { 32 };
+        let info = make_interpreter_info(3, PY3_MIN_MINOR as u32, host_bits);
+        assert_eq!(check_python_compatibility(&info), PythonCompatibility::Supported);
+    }
+
+    #[test]
+    fn test_check_python_compatibility_rejects_python2() {
+        let host_bits = if cfg!(target_pointer_width = "64") { 64 } else { 32 };
+        let info = make_interpreter_info(2, 7, host_bits);
+        assert_eq!(check_python_compatibility(&info), PythonCompatibility::TooOld);
+    }
+
+    #[test]
+    fn test_check_python_compatibility_too_old_minor() {
+        let host_bits = if cfg!(target_pointer_width = "64") { 64 } else { 32 };
+        let info = make_interpreter_info(3, PY3_MIN_MINOR as u32 - 1, host_bits);
+        assert_eq!(check_python_compatibility(&info), PythonCompatibility::TooOld);
+    }
+
+    #[test]
+    fn test_check_python_compatibility_too_new() {
+        let host_bits = if cfg!(target_pointer_width = "64") { 64 } else { 32 };
+        let info = make_interpreter_info(3, PY3_MAX_MINOR as u32 + 1, host_bits);
+        assert_eq!(check_python_compatibility(&info), PythonCompatibility::TooNew);
+    }
+
+    #[test]
+    fn test_check_python_compatibility_wrong_bitness() {
+        let mismatched_bits = if cfg!(target_pointer_width = "64") { 32 } else { 64 };
+        let info = make_interpreter_info(3, PY3_MIN_MINOR as u32, mismatched_bits);
+        assert_eq!(check_python_compatibility(&info), PythonCompatibility::WrongBitness);
+    }
+
+    #[test]
+    fn test_parse_os_release_debian() {
+        let content = "ID=debian\nID_LIKE=\nVERSION_ID=\"12\"\nPRETTY_NAME=\"Debian GNU/Linux 12 (bookworm)\"\n";
+        let info = parse_os_release(content).unwrap();
+        assert_eq!(info.id, "debian");
+        assert_eq!(info.version, "12");
+        assert_eq!(info.name, "Debian GNU/Linux 12 (bookworm)");
+        assert_eq!(info.package_manager, Some(super::super::installer::WslPackageManager::Apt));
+    }
+
+    #[test]
+    fn test_parse_os_release_centos_old_uses_yum() {
+        let content = "ID=\"centos\"\nID_LIKE=\"rhel fedora\"\nVERSION_ID=\"7\"\nPRETTY_NAME=\"CentOS Linux 7\"\n";
+        let info = parse_os_release(content).unwrap();
+        assert_eq!(info.package_manager, Some(super::super::installer::WslPackageManager::Yum));
+    }
+
+    #[test]
+    fn test_parse_os_release_rhel_8_uses_dnf() {
+        let content = "ID=\"rhel\"\nID_LIKE=\"fedora\"\nVERSION_ID=\"8.6\"\nPRETTY_NAME=\"Red Hat Enterprise Linux 8.6\"\n";
+        let info = parse_os_release(content).unwrap();
+        assert_eq!(info.package_manager, Some(super::super::installer::WslPackageManager::Dnf));
+    }
+
+    #[test]
+    fn test_parse_os_release_fedora_21_uses_yum() {
+        // Fedora switched to dnf at 22, six years before RHEL/CentOS did at
+        // 8 - a VERSION_ID-only check without an id-specific cutoff would
+        // wrongly classify this as Dnf (21 >= 8).
+        let content = "ID=fedora\nVERSION_ID=\"21\"\nPRETTY_NAME=\"Fedora 21\"\n";
+        let info = parse_os_release(content).unwrap();
+        assert_eq!(info.package_manager, Some(super::super::installer::WslPackageManager::Yum));
+    }
+
+    #[test]
+    fn test_parse_os_release_fedora_22_uses_dnf() {
+        let content = "ID=fedora\nVERSION_ID=\"22\"\nPRETTY_NAME=\"Fedora 22\"\n";
+        let info = parse_os_release(content).unwrap();
+        assert_eq!(info.package_manager, Some(super::super::installer::WslPackageManager::Dnf));
+    }
+
+    #[test]
+    fn test_parse_os_release_missing_id_returns_none() {
+        assert!(parse_os_release("VERSION_ID=\"1\"\n").is_none());
+    }
+
+    #[test]
+    fn test_detect_distro_does_not_panic() {
+        // This test depends on the environment; non-Linux hosts return None.
+        let distro = detect_distro();
+        println!("Detected distro: {:?}", distro);
+    }
+
     #[test]
     fn test_detect_python() {
         // This test depends on the environment
@@ -549,6 +1239,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_discover_interpreters_does_not_panic() {
+        // This test depends on the environment; it only checks that probing
+        // every candidate and deduplicating the results behaves.
+        let interpreters = discover_interpreters();
+        println!("Discovered interpreters: {:?}", interpreters.len());
+
+        let mut seen = std::collections::HashSet::new();
+        for info in &interpreters {
+            let key = fs::canonicalize(&info.executable).unwrap_or_else(|_| info.executable.clone());
+            assert!(seen.insert(key), "duplicate interpreter executable in discover_interpreters output");
+        }
+    }
+
     #[test]
     fn test_detect_docker() {
         let (available, running, version) = detect_docker();
@@ -564,6 +1268,8 @@ mod tests {
             python_available: true,
             python_version: Some("3.11.5".to_string()),
             python_path: Some(PathBuf::from("/usr/bin/python3")),
+            interpreter: None,
+            python_compatibility: None,
             devflow_installed: true,
             devflow_version: Some("0.1.0".to_string()),
             docker_available: true,
@@ -571,6 +1277,8 @@ mod tests {
             docker_version: Some("24.0.5".to_string()),
             wsl_available: false,
             wsl_distros: vec![],
+            running_in_container: false,
+            distro: None,
         };
 
         let json = serde_json::to_string(&status).unwrap();
@@ -579,6 +1287,14 @@ mod tests {
         assert_eq!(parsed.python_version, Some("3.11.5".to_string()));
     }
 
+    #[test]
+    fn test_translate_host_path_is_noop_outside_container() {
+        // CI/test environments aren't dockerized, so this should pass
+        // through unchanged without ever shelling out to `docker`.
+        let path = PathBuf::from("/workspace/app");
+        assert_eq!(translate_host_path(&path), path);
+    }
+
     #[test]
     fn test_wsl_distro_status_serialization() {
         let status = WslDistroStatus {
@@ -589,6 +1305,7 @@ mod tests {
             python_version: Some("3.11.5".to_string()),
             devflow_installed: true,
             devflow_version: Some("0.2.0".to_string()),
+            distro: None,
         };
 
         let json = serde_json::to_string(&status).unwrap();
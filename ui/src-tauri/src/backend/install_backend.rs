@@ -0,0 +1,496 @@
+//! Polymorphic install surface unifying the local, Docker, Docker Compose,
+//! and WSL2 install flows behind one trait, so callers (and the UI) can
+//! treat all modes identically instead of branching on `BackendType`
+//! themselves.
+
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+use super::config::{BackendConfig, BackendType};
+use super::detection::{check_devflow_installed, check_docker_container, detect_docker};
+use super::installer::{
+    check_devflow_in_wsl, compose_down, compose_service_running, compose_up_with_progress,
+    default_compose_path, install_devflow_local, install_devflow_wsl_with_progress,
+    is_port_available, parse_volume_mount, prune_docker_images, pull_docker_image_with_progress,
+    start_docker_container_with_options, start_wsl_service, stop_docker_container,
+    stop_wsl_service, validate_wsl_installation, write_compose_file, ComposeOptions,
+    DockerRunOptions, InstallResult, COMPOSE_SERVICE_NAME,
+};
+use super::python_runtime::bootstrap_standalone_python_result;
+use std::path::PathBuf;
+
+/// Backend-agnostic pre-flight validation result: human-readable issues that
+/// block installation and warnings that don't.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct InstallValidation {
+    pub can_install: bool,
+    pub issues: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// One polymorphic surface over the local, Docker, and WSL2 install targets.
+pub trait InstallBackend {
+    /// Run pre-flight checks without making any changes.
+    fn validate(&self) -> InstallValidation;
+
+    /// Install devflow, reporting progress through `on_progress`.
+    fn install(&self, on_progress: &dyn Fn(&str)) -> InstallResult;
+
+    /// The installed devflow version, if any.
+    fn installed_version(&self) -> Option<String>;
+
+    /// Remove devflow (and, for Docker/WSL2, stop the associated service).
+    /// The WSL2 implementation only stops the bridge service; the devflow
+    /// package stays installed in the distro.
+    fn uninstall(&self) -> InstallResult;
+}
+
+/// Install devflow into a local Python environment.
+pub struct LocalBackend {
+    pub python_path: Option<std::path::PathBuf>,
+}
+
+impl InstallBackend for LocalBackend {
+    fn validate(&self) -> InstallValidation {
+        // Nothing blocks local install: `install_devflow_local` bootstraps a
+        // standalone Python itself when none is found.
+        let mut warnings = Vec::new();
+        let (installed, version) = check_devflow_installed(self.python_path.as_ref());
+        if installed {
+            warnings.push(match version {
+                Some(v) => format!("DevFlow {} is already installed", v),
+                None => "DevFlow is already installed".to_string(),
+            });
+        }
+
+        InstallValidation {
+            can_install: true,
+            issues: vec![],
+            warnings,
+        }
+    }
+
+    fn install(&self, on_progress: &dyn Fn(&str)) -> InstallResult {
+        on_progress("Installing devflow locally...");
+        let result = install_devflow_local(self.python_path.as_ref());
+        on_progress(&result.message);
+        result
+    }
+
+    fn installed_version(&self) -> Option<String> {
+        check_devflow_installed(self.python_path.as_ref()).1
+    }
+
+    fn uninstall(&self) -> InstallResult {
+        let python = self
+            .python_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| if cfg!(windows) { "python".to_string() } else { "python3".to_string() });
+
+        match Command::new(&python).args(["-m", "pip", "uninstall", "-y", "devflow"]).output() {
+            Ok(o) if o.status.success() => InstallResult::ok("DevFlow uninstalled"),
+            Ok(o) => InstallResult::err(format!(
+                "Failed to uninstall: {}",
+                String::from_utf8_lossy(&o.stderr)
+            )),
+            Err(e) => InstallResult::err(format!("Failed to run pip: {}", e)),
+        }
+    }
+}
+
+/// Install devflow as a Docker container.
+pub struct DockerBackend {
+    pub container_name: String,
+    pub port: u16,
+    pub run_options: DockerRunOptions,
+}
+
+impl InstallBackend for DockerBackend {
+    fn validate(&self) -> InstallValidation {
+        let mut issues = Vec::new();
+        let mut warnings = Vec::new();
+
+        let (docker_available, docker_running, _version) = detect_docker();
+        if !docker_available {
+            issues.push("Docker is not installed".to_string());
+        } else if !docker_running {
+            issues.push("Docker daemon is not running".to_string());
+        }
+
+        let (exists, running) = check_docker_container(&self.container_name);
+        if exists && running {
+            warnings.push(format!(
+                "Container '{}' already exists and is running",
+                self.container_name
+            ));
+        } else if !is_port_available(self.port) {
+            issues.push(format!("Port {} is already in use", self.port));
+        }
+
+        InstallValidation {
+            can_install: issues.is_empty(),
+            issues,
+            warnings,
+        }
+    }
+
+    fn install(&self, on_progress: &dyn Fn(&str)) -> InstallResult {
+        if self.run_options.should_pull() {
+            on_progress(&format!("Pulling image {}...", self.run_options.image_ref));
+            let pull_result =
+                pull_docker_image_with_progress(&self.run_options.image_ref, |msg| on_progress(msg));
+            if !pull_result.success {
+                return pull_result;
+            }
+
+            if self.run_options.prune_after_pull {
+                on_progress("Pruning superseded devflow images...");
+                let prune_result = prune_docker_images(false, on_progress);
+                if !prune_result.success {
+                    log::warn!("Prune after pull failed: {}", prune_result.message);
+                }
+            }
+        } else {
+            on_progress(&format!(
+                "Using local image {} (skipping pull)",
+                self.run_options.image_ref
+            ));
+        }
+
+        on_progress(&format!("Starting container '{}'...", self.container_name));
+        start_docker_container_with_options(&self.container_name, self.port, &self.run_options)
+    }
+
+    fn installed_version(&self) -> Option<String> {
+        let (exists, running) = check_docker_container(&self.container_name);
+        if !exists || !running {
+            return None;
+        }
+
+        Command::new("docker")
+            .args([
+                "exec",
+                &self.container_name,
+                "python3",
+                "-c",
+                "import devflow; print(devflow.__version__)",
+            ])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    }
+
+    fn uninstall(&self) -> InstallResult {
+        let stop = stop_docker_container(&self.container_name);
+        if !stop.success {
+            log::warn!("Failed to stop container before removal: {}", stop.message);
+        }
+
+        let output = Command::new("docker").args(["rm", "-f", &self.container_name]).output();
+        match output {
+            Ok(o) if o.status.success() => InstallResult::ok("Container removed"),
+            Ok(o) => InstallResult::err(format!(
+                "Failed to remove container: {}",
+                String::from_utf8_lossy(&o.stderr)
+            )),
+            Err(e) => InstallResult::err(format!("Failed to run docker: {}", e)),
+        }
+    }
+}
+
+/// Install devflow as the `devflow` service of a Docker Compose project,
+/// alongside whatever sidecar services (db, cache, worker) the user has
+/// added to the compose file by hand.
+pub struct DockerComposeBackend {
+    pub compose_path: PathBuf,
+    pub compose_options: ComposeOptions,
+}
+
+impl InstallBackend for DockerComposeBackend {
+    fn validate(&self) -> InstallValidation {
+        let mut issues = Vec::new();
+        let mut warnings = Vec::new();
+
+        let (docker_available, docker_running, _version) = detect_docker();
+        if !docker_available {
+            issues.push("Docker is not installed".to_string());
+        } else if !docker_running {
+            issues.push("Docker daemon is not running".to_string());
+        }
+
+        if self.compose_path.exists() {
+            warnings.push(format!(
+                "Reusing existing compose file at {}",
+                self.compose_path.display()
+            ));
+        } else if !is_port_available(self.compose_options.port) {
+            issues.push(format!("Port {} is already in use", self.compose_options.port));
+        }
+
+        InstallValidation {
+            can_install: issues.is_empty(),
+            issues,
+            warnings,
+        }
+    }
+
+    fn install(&self, on_progress: &dyn Fn(&str)) -> InstallResult {
+        if !self.compose_path.exists() {
+            on_progress(&format!("Generating compose file at {}...", self.compose_path.display()));
+            if let Err(e) = write_compose_file(&self.compose_path, &self.compose_options) {
+                return InstallResult::err(e);
+            }
+        } else {
+            on_progress(&format!("Using existing compose file at {}...", self.compose_path.display()));
+        }
+
+        on_progress("Running docker compose up -d...");
+        compose_up_with_progress(&self.compose_path, |msg| on_progress(msg))
+    }
+
+    fn installed_version(&self) -> Option<String> {
+        if !compose_service_running(&self.compose_path, COMPOSE_SERVICE_NAME) {
+            return None;
+        }
+
+        Command::new("docker")
+            .args([
+                "compose",
+                "-f",
+                &self.compose_path.to_string_lossy(),
+                "exec",
+                "-T",
+                COMPOSE_SERVICE_NAME,
+                "python3",
+                "-c",
+                "import devflow; print(devflow.__version__)",
+            ])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    }
+
+    fn uninstall(&self) -> InstallResult {
+        compose_down(&self.compose_path)
+    }
+}
+
+/// Install devflow into a WSL2 distribution.
+pub struct WslBackend {
+    pub distro: String,
+    pub port: u16,
+}
+
+impl InstallBackend for WslBackend {
+    fn validate(&self) -> InstallValidation {
+        let validation = validate_wsl_installation(&self.distro, self.port);
+        InstallValidation {
+            can_install: validation.can_install,
+            issues: validation.issues.iter().map(|i| i.message()).collect(),
+            warnings: validation.warnings,
+        }
+    }
+
+    fn install(&self, on_progress: &dyn Fn(&str)) -> InstallResult {
+        let install_result =
+            install_devflow_wsl_with_progress(&self.distro, |msg| on_progress(msg));
+        if !install_result.success {
+            return install_result;
+        }
+
+        on_progress(&format!("Starting DevFlow service on port {}...", self.port));
+        start_wsl_service(&self.distro, self.port)
+    }
+
+    fn installed_version(&self) -> Option<String> {
+        check_devflow_in_wsl(&self.distro).1
+    }
+
+    fn uninstall(&self) -> InstallResult {
+        stop_wsl_service(&self.distro, self.port)
+    }
+}
+
+/// Install a "backend" that's really just a pointer at an already-running
+/// remote DevFlow instance. There's nothing to install or uninstall; the
+/// validation is just a connectivity probe.
+pub struct RemoteBackend {
+    pub host: String,
+    pub port: u16,
+}
+
+impl InstallBackend for RemoteBackend {
+    fn validate(&self) -> InstallValidation {
+        let reachable = super::detection::test_devflow_connection(&self.host, self.port);
+        InstallValidation {
+            can_install: reachable,
+            issues: if reachable {
+                vec![]
+            } else {
+                vec![format!("Cannot reach {}:{}", self.host, self.port)]
+            },
+            warnings: vec![],
+        }
+    }
+
+    fn install(&self, on_progress: &dyn Fn(&str)) -> InstallResult {
+        on_progress(&format!("Checking remote backend at {}:{}...", self.host, self.port));
+        if super::detection::test_devflow_connection(&self.host, self.port) {
+            InstallResult::ok("Remote backend is accessible")
+        } else {
+            InstallResult::err(format!("Cannot connect to remote backend at {}:{}", self.host, self.port))
+        }
+    }
+
+    fn installed_version(&self) -> Option<String> {
+        None
+    }
+
+    fn uninstall(&self) -> InstallResult {
+        InstallResult::ok("Remote backend is managed externally; nothing to uninstall")
+    }
+}
+
+/// Build `DockerRunOptions` from a `BackendConfig`'s Docker fields. Mount
+/// specs that fail to parse are logged as a warning and dropped rather than
+/// failing backend selection outright; `DockerBackend::validate`/`install`
+/// only see the mounts that parsed, so a bad spec is not reported there.
+fn docker_run_options_from_config(config: &BackendConfig) -> DockerRunOptions {
+    let extra_mounts = config
+        .docker_mounts
+        .iter()
+        .filter_map(|spec| match parse_volume_mount(spec) {
+            Ok(mount) => Some(mount),
+            Err(e) => {
+                log::warn!("Skipping invalid docker mount: {}", e);
+                None
+            }
+        })
+        .collect();
+
+    DockerRunOptions {
+        extra_mounts,
+        memory: config.docker_memory.clone(),
+        cpus: config.docker_cpus.clone(),
+        shm_size: config.docker_shm_size.clone(),
+        network_mode: config.docker_network_mode.clone(),
+        ..DockerRunOptions::default()
+    }
+}
+
+/// Resolve the compose file path and `devflow` service options for a
+/// DockerCompose `BackendConfig`, falling back to `~/.devflow/docker-compose.yml`
+/// and the config's TCP port/image/restart policy when not overridden.
+fn docker_compose_settings_from_config(config: &BackendConfig) -> (PathBuf, ComposeOptions) {
+    let compose_path = config
+        .compose_path
+        .clone()
+        .or_else(default_compose_path)
+        .unwrap_or_else(|| PathBuf::from("docker-compose.yml"));
+
+    let mut options = ComposeOptions {
+        port: config.tcp_port(),
+        ..ComposeOptions::default()
+    };
+    if let Some(ref image) = config.compose_image {
+        options.image_ref = image.clone();
+    }
+    if let Some(ref policy) = config.compose_restart_policy {
+        options.restart_policy = policy.clone();
+    }
+
+    (compose_path, options)
+}
+
+/// Build the `InstallBackend` for a given config. If no system Python is
+/// resolved for `LocalPython`, the backend still works - `install()` will
+/// bootstrap a standalone CPython (see `python_runtime`) on demand.
+pub fn select_backend(config: &BackendConfig) -> Box<dyn InstallBackend> {
+    match config.backend_type {
+        BackendType::LocalPython => Box::new(LocalBackend {
+            python_path: config.python_path.clone(),
+        }),
+        BackendType::Docker => Box::new(DockerBackend {
+            container_name: config
+                .container_name
+                .clone()
+                .unwrap_or_else(|| "devflow-backend".to_string()),
+            port: config.tcp_port(),
+            run_options: docker_run_options_from_config(config),
+        }),
+        BackendType::DockerCompose => {
+            let (compose_path, compose_options) = docker_compose_settings_from_config(config);
+            Box::new(DockerComposeBackend {
+                compose_path,
+                compose_options,
+            })
+        }
+        BackendType::Wsl2 => Box::new(WslBackend {
+            distro: config.wsl_distro.clone().unwrap_or_else(|| "Ubuntu".to_string()),
+            port: config.tcp_port(),
+        }),
+        BackendType::Remote => Box::new(RemoteBackend {
+            host: config.tcp_host(),
+            port: config.tcp_port(),
+        }),
+    }
+}
+
+/// Ensure the standalone Python path is reachable from this module too, so
+/// `LocalBackend` consumers that want to pre-warm it don't need to reach
+/// into `python_runtime` directly.
+pub fn bootstrap_local_python() -> InstallResult {
+    bootstrap_standalone_python_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_backend_handles_every_config_type() {
+        let local = select_backend(&BackendConfig::local_python(None));
+        assert!(local.installed_version().is_none() || local.installed_version().is_some());
+
+        let docker = select_backend(&BackendConfig::docker(Some("test-container".to_string())));
+        let validation = docker.validate();
+        // Should not panic regardless of whether Docker is present in CI.
+        println!("Docker validation: {:?}", validation);
+
+        let compose = select_backend(&BackendConfig::docker_compose(None));
+        let validation = compose.validate();
+        println!("DockerCompose validation: {:?}", validation);
+
+        let wsl = select_backend(&BackendConfig::wsl2(Some("Ubuntu".to_string())));
+        let validation = wsl.validate();
+        println!("WSL validation: {:?}", validation);
+
+        let remote = select_backend(&BackendConfig::remote("127.0.0.1".to_string(), 9876));
+        let validation = remote.validate();
+        println!("Remote validation: {:?}", validation);
+    }
+
+    #[test]
+    fn test_docker_compose_settings_from_config_applies_overrides() {
+        let mut config = BackendConfig::docker_compose(Some(PathBuf::from("/tmp/devflow-compose.yml")));
+        config.remote_port = Some(12345);
+        config.compose_image = Some("ghcr.io/ao-cyber-systems/devflow:dev".to_string());
+        config.compose_restart_policy = Some("always".to_string());
+
+        let (compose_path, options) = docker_compose_settings_from_config(&config);
+        assert_eq!(compose_path, PathBuf::from("/tmp/devflow-compose.yml"));
+        assert_eq!(options.port, 12345);
+        assert_eq!(options.image_ref, "ghcr.io/ao-cyber-systems/devflow:dev");
+        assert_eq!(options.restart_policy, "always");
+    }
+
+    #[test]
+    fn test_docker_compose_settings_from_config_defaults_path() {
+        let config = BackendConfig::docker_compose(None);
+        let (compose_path, _options) = docker_compose_settings_from_config(&config);
+        assert!(compose_path.ends_with("docker-compose.yml"));
+    }
+}
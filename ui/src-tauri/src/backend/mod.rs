@@ -5,8 +5,18 @@
 
 pub mod config;
 pub mod detection;
+pub mod install_backend;
 pub mod installer;
+pub mod python_runtime;
 
 pub use config::*;
 pub use detection::*;
+pub use install_backend::{
+    select_backend, DockerBackend, DockerComposeBackend, InstallBackend, InstallValidation,
+    LocalBackend, RemoteBackend, WslBackend,
+};
 pub use installer::*;
+pub use python_runtime::{
+    bootstrap_python, bootstrap_standalone_python, bootstrap_standalone_python_result,
+    managed_python_path,
+};
@@ -0,0 +1,295 @@
+//! Standalone CPython bootstrap - downloads a self-contained interpreter so
+//! installation doesn't depend on whatever Python the system happens to ship.
+//!
+//! Uses the `python-build-standalone` project's release assets
+//! (`cpython-<ver>-<triple>-install_only.tar.gz`), which are plain tarballs
+//! with no installer, licensing prompt, or registry footprint.
+
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use super::detection::{probe_interpreter, InterpreterInfo};
+use super::installer::InstallResult;
+
+/// python-build-standalone release tag to pin. Bump deliberately; this also
+/// pins the CPython version we bootstrap.
+const RELEASE_TAG: &str = "20240909";
+const PYTHON_VERSION: &str = "3.11.9";
+
+/// Base URL for release assets.
+const RELEASE_BASE_URL: &str =
+    "https://github.com/indygreg/python-build-standalone/releases/download";
+
+/// Map Rust's `std::env::consts::{OS, ARCH}` to the target triple used in
+/// python-build-standalone asset names.
+fn target_triple() -> Option<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => Some("x86_64-unknown-linux-gnu"),
+        ("linux", "aarch64") => Some("aarch64-unknown-linux-gnu"),
+        ("macos", "x86_64") => Some("x86_64-apple-darwin"),
+        ("macos", "aarch64") => Some("aarch64-apple-darwin"),
+        ("windows", "x86_64") => Some("x86_64-pc-windows-msvc"),
+        ("windows", "aarch64") => Some("aarch64-pc-windows-msvc"),
+        _ => None,
+    }
+}
+
+/// Asset file name for this platform.
+fn asset_name() -> Option<String> {
+    let triple = target_triple()?;
+    Some(format!(
+        "cpython-{PYTHON_VERSION}+{RELEASE_TAG}-{triple}-install_only.tar.gz"
+    ))
+}
+
+/// Overrides the install root returned by `runtime_root()`, mainly so tests
+/// and packagers can point the bootstrap somewhere other than the real home
+/// directory.
+const PYTHON_ROOT_ENV_VAR: &str = "DEVFLOW_PYTHON_ROOT";
+
+/// Directory the standalone runtime is unpacked into. Defaults to
+/// `~/.devflow/python`, overridable via `DEVFLOW_PYTHON_ROOT`.
+fn runtime_root() -> Option<PathBuf> {
+    if let Ok(root) = std::env::var(PYTHON_ROOT_ENV_VAR) {
+        return Some(PathBuf::from(root));
+    }
+    dirs::home_dir().map(|home| home.join(".devflow").join("python"))
+}
+
+/// Path to the embedded interpreter once extracted, relative to
+/// `runtime_root()/python/...` as laid out by the `install_only` archives.
+fn interpreter_path(root: &std::path::Path) -> PathBuf {
+    if cfg!(windows) {
+        root.join("python").join("python.exe")
+    } else {
+        root.join("python").join("bin").join("python3")
+    }
+}
+
+/// Download the release's `SHA256SUMS` file and find the digest for `name`.
+fn fetch_expected_sha256(name: &str) -> Result<String, String> {
+    let url = format!("{RELEASE_BASE_URL}/{RELEASE_TAG}/SHA256SUMS");
+    let body = reqwest::blocking::get(&url)
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.text())
+        .map_err(|e| format!("Failed to fetch checksums: {}", e))?;
+
+    body.lines()
+        .find_map(|line| {
+            let mut parts = line.split_whitespace();
+            let digest = parts.next()?;
+            let file = parts.next()?;
+            (file == name).then(|| digest.to_string())
+        })
+        .ok_or_else(|| format!("No checksum entry for {}", name))
+}
+
+/// Download `url` to `dest`, returning the sha256 of the downloaded bytes.
+fn download_to_file(url: &str, dest: &std::path::Path) -> Result<String, String> {
+    let response = reqwest::blocking::get(url)
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download {}: {}", url, e))?;
+
+    let bytes = response
+        .bytes()
+        .map_err(|e| format!("Failed to read download body: {}", e))?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    let digest = format!("{:x}", hasher.finalize());
+
+    let mut file =
+        fs::File::create(dest).map_err(|e| format!("Failed to create {}: {}", dest.display(), e))?;
+    file.write_all(&bytes)
+        .map_err(|e| format!("Failed to write {}: {}", dest.display(), e))?;
+
+    Ok(digest)
+}
+
+/// Extract a `.tar.gz` archive into `dest_dir`.
+fn extract_tar_gz(archive: &std::path::Path, dest_dir: &std::path::Path) -> Result<(), String> {
+    let file =
+        fs::File::open(archive).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let decoder = flate2::read::GzDecoder::new(file);
+    let mut archive = tar::Archive::new(decoder);
+    archive
+        .unpack(dest_dir)
+        .map_err(|e| format!("Failed to extract archive: {}", e))
+}
+
+/// Download, verify, and extract a standalone CPython build into
+/// `~/.devflow/python`, returning the path to the embedded interpreter.
+///
+/// If a suitable interpreter is already extracted at that location, it's
+/// reused without re-downloading.
+pub fn bootstrap_standalone_python() -> Result<PathBuf, String> {
+    let root = runtime_root().ok_or("Could not determine home directory")?;
+    let python = interpreter_path(&root);
+
+    if python.is_file() {
+        log::info!("Standalone Python already bootstrapped at {}", python.display());
+        return Ok(python);
+    }
+
+    let name = asset_name().ok_or_else(|| {
+        format!(
+            "No standalone Python build available for {}/{}",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )
+    })?;
+
+    fs::create_dir_all(&root)
+        .map_err(|e| format!("Failed to create {}: {}", root.display(), e))?;
+
+    let url = format!("{RELEASE_BASE_URL}/{RELEASE_TAG}/{name}");
+    let archive_path = root.join(&name);
+
+    log::info!("Downloading standalone Python from {}", url);
+    let actual_sha256 = download_to_file(&url, &archive_path)?;
+
+    let expected_sha256 = fetch_expected_sha256(&name)?;
+    if !actual_sha256.eq_ignore_ascii_case(&expected_sha256) {
+        let _ = fs::remove_file(&archive_path);
+        return Err(format!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            name, expected_sha256, actual_sha256
+        ));
+    }
+
+    log::info!("Extracting standalone Python into {}", root.display());
+    extract_tar_gz(&archive_path, &root)?;
+    let _ = fs::remove_file(&archive_path);
+
+    if !python.is_file() {
+        return Err(format!(
+            "Extraction succeeded but interpreter not found at {}",
+            python.display()
+        ));
+    }
+
+    Ok(python)
+}
+
+/// Path to a previously-bootstrapped standalone interpreter, if one has
+/// already been provisioned at `runtime_root()`. Callers that need a working
+/// Python (e.g. `installer::resolve_python`) check this before falling back
+/// to system detection, so a managed interpreter is reused rather than
+/// re-discovered on every install.
+pub fn managed_python_path() -> Option<PathBuf> {
+    let root = runtime_root()?;
+    let python = interpreter_path(&root);
+    python.is_file().then_some(python)
+}
+
+/// Download, verify, and extract a standalone CPython build, then re-probe
+/// it to return a full `InterpreterInfo` with `managed` set, instead of a
+/// bare path. This is the entry point for the "install Python for me" flow
+/// offered when `detect_python()` finds nothing usable.
+pub fn bootstrap_python() -> Result<InterpreterInfo, String> {
+    let python = bootstrap_standalone_python()?;
+    let mut info = probe_interpreter(&python).ok_or_else(|| {
+        format!(
+            "Bootstrapped interpreter at {} could not be probed",
+            python.display()
+        )
+    })?;
+    info.managed = true;
+    Ok(info)
+}
+
+/// Like `bootstrap_standalone_python`, wrapped as an `InstallResult` for
+/// callers that want the same success/message shape as the rest of the
+/// installer module.
+pub fn bootstrap_standalone_python_result() -> InstallResult {
+    match bootstrap_standalone_python() {
+        Ok(path) => InstallResult::ok_with_version(
+            format!("Standalone Python ready at {}", path.display()),
+            PYTHON_VERSION.to_string(),
+        ),
+        Err(e) => InstallResult::err(e),
+    }
+}
+
+/// Run the standalone Python bootstrap inside a WSL2 distro instead of on
+/// the Windows host, since the interpreter needs to run Linux binaries.
+#[cfg(windows)]
+pub fn bootstrap_standalone_python_wsl(distro: &str) -> Result<PathBuf, String> {
+    use super::installer::run_wsl_command;
+
+    let triple = "x86_64-unknown-linux-gnu";
+    let name = format!("cpython-{PYTHON_VERSION}+{RELEASE_TAG}-{triple}-install_only.tar.gz");
+    let url = format!("{RELEASE_BASE_URL}/{RELEASE_TAG}/{name}");
+
+    let cmd = format!(
+        "mkdir -p ~/.devflow/python && cd ~/.devflow/python && \
+         (test -x python/bin/python3 || \
+          (curl -fsSL -o {name} {url} && tar xzf {name} && rm {name})) && \
+         echo \"$HOME/.devflow/python/python/bin/python3\"",
+        name = name,
+        url = url
+    );
+
+    let output = run_wsl_command(distro, &cmd)
+        .map_err(|e| format!("Failed to run wsl: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if path.is_empty() {
+        return Err("WSL bootstrap produced no interpreter path".to_string());
+    }
+    Ok(PathBuf::from(path))
+}
+
+#[cfg(not(windows))]
+pub fn bootstrap_standalone_python_wsl(_distro: &str) -> Result<PathBuf, String> {
+    Err("WSL is only available on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_triple_known_platforms() {
+        // Just verify the function doesn't panic and returns something
+        // sensible for the platform running the tests.
+        let triple = target_triple();
+        if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+            assert_eq!(triple, Some("x86_64-unknown-linux-gnu"));
+        }
+    }
+
+    #[test]
+    fn test_asset_name_format() {
+        if let Some(name) = asset_name() {
+            assert!(name.starts_with("cpython-"));
+            assert!(name.ends_with("-install_only.tar.gz"));
+            assert!(name.contains(PYTHON_VERSION));
+        }
+    }
+
+    #[test]
+    fn test_managed_python_path_absent_by_default() {
+        // Point the root at a directory that can't contain a bootstrapped
+        // interpreter, so this doesn't depend on the test machine's state.
+        std::env::set_var(PYTHON_ROOT_ENV_VAR, "/nonexistent/devflow-python-test-root");
+        assert_eq!(managed_python_path(), None);
+        std::env::remove_var(PYTHON_ROOT_ENV_VAR);
+    }
+
+    #[test]
+    fn test_runtime_root_honors_env_override() {
+        std::env::set_var(PYTHON_ROOT_ENV_VAR, "/tmp/devflow-python-test-root");
+        assert_eq!(
+            runtime_root(),
+            Some(PathBuf::from("/tmp/devflow-python-test-root"))
+        );
+        std::env::remove_var(PYTHON_ROOT_ENV_VAR);
+    }
+}
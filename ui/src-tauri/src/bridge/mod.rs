@@ -1,7 +1,17 @@
 pub mod rpc;
 pub mod sidecar;
 pub mod tcp;
+pub mod transport;
 
 // Re-export commonly used types
-pub use sidecar::{BridgeManager, ConnectionMode};
+pub use sidecar::{
+    BridgeConnection, BridgeManager, ConnectionMode, ConnectionStatus, ExecSessionInfo, MethodStats,
+};
 pub use tcp::TcpRpcClient;
+pub use transport::{ChildPipeTransport, Transport};
+
+#[cfg(unix)]
+pub use transport::UnixSocketTransport;
+
+#[cfg(windows)]
+pub use transport::NamedPipeTransport;
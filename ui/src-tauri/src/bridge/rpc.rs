@@ -1,11 +1,27 @@
+use super::transport::{ChildPipeTransport, Transport};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::io::{BufRead, BufReader, Write};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
 use std::process::{ChildStdin, ChildStdout};
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 use thiserror::Error;
 
+const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// This client's protocol version, sent as the `version` param of `rpc.hello`.
+/// Encoded as `major * 100 + minor`: backends with a different `major` are
+/// rejected outright, while a higher `minor` is assumed backwards compatible.
+pub const PROTOCOL_VERSION: u32 = 100;
+
+fn protocol_major(version: u32) -> u32 {
+    version / 100
+}
+
 #[derive(Error, Debug)]
 pub enum RpcError {
     #[error("IO error: {0}")]
@@ -26,6 +42,20 @@ pub enum RpcError {
 
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
+
+    #[error("RPC call to {method} timed out after {elapsed:?}")]
+    Timeout { method: String, elapsed: Duration },
+
+    #[error("RPC call was cancelled")]
+    Cancelled,
+
+    #[error(
+        "Bridge protocol version {server} is incompatible with client version {client}"
+    )]
+    IncompatibleVersion { client: u32, server: u32 },
+
+    #[error("bridge too old, method unsupported: {0}")]
+    UnsupportedMethod(String),
 }
 
 #[derive(Debug, Serialize)]
@@ -62,84 +92,634 @@ pub struct RpcErrorObject {
     pub data: Option<Value>,
 }
 
+/// A line received off the background reader: either a response to a pending
+/// call (carries an `id`) or a server-initiated notification (no `id`, has a
+/// `method`).
+#[derive(Debug, Deserialize)]
+struct RpcLine {
+    #[allow(dead_code)]
+    jsonrpc: String,
+    method: Option<String>,
+    result: Option<Value>,
+    error: Option<RpcErrorObject>,
+    id: Option<u64>,
+    params: Option<Value>,
+}
+
+/// What to do with the response once a pending request's reply line arrives.
+enum PendingEntry {
+    /// A plain `call`: deliver the result to the waiting caller.
+    Call(Sender<Result<Value, RpcError>>),
+    /// A `subscribe`: the result is the new subscription id. Register the
+    /// notification sender under that id *before* waking the caller, so a
+    /// notification that arrives the instant the subscription is confirmed
+    /// can never race ahead of the registration.
+    Subscribe(Sender<Result<Value, RpcError>>, Sender<Value>),
+}
+
+type PendingMap = Mutex<HashMap<u64, PendingEntry>>;
+type SubscriptionMap = Mutex<HashMap<u64, Sender<Value>>>;
+
+/// The negotiated outcome of `rpc.hello`: the backend's protocol version and
+/// the set of methods it advertised, so `supports` can answer without a
+/// round trip.
+#[derive(Debug, Clone, Default)]
+struct Handshake {
+    version: u32,
+    methods: HashSet<String>,
+}
+
+/// Parse a `rpc.hello` result of the form `{ "version": u32, "methods": [...]
+/// }` and check it against `PROTOCOL_VERSION` for major-version compatibility.
+fn parse_hello(result: &Value) -> Result<Handshake, RpcError> {
+    let version = result
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| RpcError::InvalidResponse("rpc.hello missing version".to_string()))?
+        as u32;
+
+    if protocol_major(version) != protocol_major(PROTOCOL_VERSION) {
+        return Err(RpcError::IncompatibleVersion {
+            client: PROTOCOL_VERSION,
+            server: version,
+        });
+    }
+
+    let methods = result
+        .get("methods")
+        .and_then(Value::as_array)
+        .map(|methods| {
+            methods
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(Handshake { version, methods })
+}
+
+/// RPC client over a pluggable [`Transport`] (child process pipes, a Unix
+/// socket, or a TCP socket).
+///
+/// A single background thread owns the transport's reader half and reads
+/// every line as soon as it arrives, dispatching the result to whichever
+/// `call` is waiting on that request id. This lets multiple `call`s be
+/// in flight at once instead of each one locking the reader and assuming the
+/// very next line is its own response.
+///
+/// Lines with no `id` are notifications. Ones whose `params.subscription`
+/// matches an open `subscribe()` are forwarded to that subscription's
+/// receiver; others are logged and dropped.
 pub struct RpcClient {
-    stdin: Mutex<Option<ChildStdin>>,
-    stdout: Mutex<Option<BufReader<ChildStdout>>>,
+    writer: Mutex<Option<Box<dyn Write + Send>>>,
+    connected: Arc<AtomicBool>,
     request_id: AtomicU64,
+    pending: Arc<PendingMap>,
+    subscriptions: Arc<SubscriptionMap>,
+    reader_handle: Mutex<Option<thread::JoinHandle<()>>>,
+    /// Set by the mandatory `rpc.hello` handshake `connect_transport` performs
+    /// once the reader thread is up. `None` before the first handshake.
+    handshake: Mutex<Option<Handshake>>,
 }
 
 impl RpcClient {
     pub fn new() -> Self {
         Self {
-            stdin: Mutex::new(None),
-            stdout: Mutex::new(None),
+            writer: Mutex::new(None),
+            connected: Arc::new(AtomicBool::new(false)),
             request_id: AtomicU64::new(1),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            reader_handle: Mutex::new(None),
+            handshake: Mutex::new(None),
+        }
+    }
+
+    /// Connect over a child process's stdin/stdout pipes. Convenience
+    /// wrapper around `connect_transport` for the common local-subprocess
+    /// case.
+    pub fn connect(&self, stdin: ChildStdin, stdout: ChildStdout) -> io::Result<()> {
+        self.connect_transport(Box::new(ChildPipeTransport::new(stdin, stdout)))
+    }
+
+    /// Connect over any `Transport`, replacing any existing connection.
+    pub fn connect_transport(&self, transport: Box<dyn Transport>) -> io::Result<()> {
+        // Tear down any previous reader thread first so it can't keep running
+        // against a stale connection and clobber state shared with this one.
+        self.disconnect();
+
+        let (writer, mut reader) = transport.split()?;
+        *self.writer.lock().unwrap() = Some(writer);
+        self.connected.store(true, Ordering::SeqCst);
+
+        let pending = Arc::clone(&self.pending);
+        let subscriptions = Arc::clone(&self.subscriptions);
+        let connected = Arc::clone(&self.connected);
+
+        let handle = thread::spawn(move || loop {
+            let mut line = String::new();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => {
+                    connected.store(false, Ordering::SeqCst);
+                    Self::fail_all_pending(&pending, RpcError::NotConnected);
+                    subscriptions.lock().unwrap().clear();
+                    break;
+                }
+                Ok(_) => {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    log::debug!("RPC response: {}", line.trim());
+                    Self::dispatch_line(&pending, &subscriptions, &line);
+                }
+            }
+        });
+
+        *self.reader_handle.lock().unwrap() = Some(handle);
+
+        if let Err(e) = self.handshake() {
+            log::error!("Bridge handshake failed: {}", e);
+            self.disconnect();
+            // Wrap the typed `RpcError` itself rather than just its message, so
+            // a caller that cares (e.g. `BridgeManager` distinguishing a
+            // version mismatch from a plain connection failure) can recover it
+            // with `io::Error::get_ref` + `downcast_ref`.
+            return Err(io::Error::new(io::ErrorKind::Other, e));
+        }
+
+        Ok(())
+    }
+
+    /// Negotiate the protocol version and capability set with the backend by
+    /// calling `rpc.hello`. Performed automatically by `connect_transport`;
+    /// exposed separately so a caller can re-run it (e.g. after the backend
+    /// restarts without a fresh `connect_transport`).
+    ///
+    /// Rejects the handshake outright (without storing anything) if the
+    /// backend's major protocol version doesn't match ours, so a mismatched
+    /// backend fails here instead of with a confusing mid-call error later.
+    pub fn handshake(&self) -> Result<(), RpcError> {
+        let result = self.call("rpc.hello", Some(serde_json::json!({ "version": PROTOCOL_VERSION })))?;
+        let handshake = parse_hello(&result)?;
+        *self.handshake.lock().unwrap() = Some(handshake);
+        Ok(())
+    }
+
+    /// Whether the negotiated handshake advertised `method`. `false` before a
+    /// handshake has completed.
+    pub fn supports(&self, method: &str) -> bool {
+        self.handshake
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|h| h.methods.contains(method))
+    }
+
+    /// The protocol version the backend reported during the handshake, if one
+    /// has completed.
+    pub fn negotiated_version(&self) -> Option<u32> {
+        self.handshake.lock().unwrap().as_ref().map(|h| h.version)
+    }
+
+    /// Like `call`, but fails fast with `RpcError::UnsupportedMethod` if the
+    /// handshake didn't advertise `method`, instead of letting an unsupported
+    /// call surface as a confusing error from the backend itself.
+    pub fn call_checked(&self, method: &str, params: Option<Value>) -> Result<Value, RpcError> {
+        if !self.supports(method) {
+            return Err(RpcError::UnsupportedMethod(method.to_string()));
+        }
+        self.call(method, params)
+    }
+
+    /// Dispatch one line off the reader: either a single JSON-RPC object, or
+    /// a JSON array of objects answering a `call_batch` request.
+    fn dispatch_line(pending: &PendingMap, subscriptions: &SubscriptionMap, line: &str) {
+        let parsed: Result<Value, _> = serde_json::from_str(line);
+        let value = match parsed {
+            Ok(v) => v,
+            Err(e) => {
+                log::warn!("Failed to parse RPC line: {} ({})", line.trim(), e);
+                return;
+            }
+        };
+
+        match value {
+            Value::Array(items) => {
+                for item in items {
+                    Self::dispatch_response(pending, subscriptions, item);
+                }
+            }
+            // A bare object with `id: null` and an `error` is the JSON-RPC
+            // batch-level error case: the whole batch couldn't be parsed as a
+            // request, so there's no id to route it to any one call. Fail
+            // every in-flight call rather than silently dropping it.
+            other
+                if other.get("id").is_some_and(Value::is_null) && other.get("error").is_some() =>
+            {
+                if let Some(error) = other
+                    .get("error")
+                    .and_then(|e| serde_json::from_value::<RpcErrorObject>(e.clone()).ok())
+                {
+                    Self::fail_all_pending(
+                        pending,
+                        RpcError::Rpc {
+                            code: error.code,
+                            message: error.message,
+                            data: error.data,
+                        },
+                    );
+                } else {
+                    Self::dispatch_response(pending, subscriptions, other);
+                }
+            }
+            other => Self::dispatch_response(pending, subscriptions, other),
+        }
+    }
+
+    /// Handle a single JSON-RPC response or notification object, whether it
+    /// arrived on its own line or as one element of a batch array.
+    fn dispatch_response(pending: &PendingMap, subscriptions: &SubscriptionMap, item: Value) {
+        let parsed: Result<RpcLine, _> = serde_json::from_value(item);
+        let parsed = match parsed {
+            Ok(p) => p,
+            Err(e) => {
+                log::warn!("Failed to parse RPC response: {}", e);
+                return;
+            }
+        };
+
+        let Some(id) = parsed.id else {
+            Self::dispatch_notification(subscriptions, parsed.method, parsed.params);
+            return;
+        };
+
+        let entry = pending.lock().unwrap().remove(&id);
+        let Some(entry) = entry else {
+            log::debug!("Received response for unknown/expired request id {}", id);
+            return;
+        };
+
+        let result = if let Some(error) = parsed.error {
+            Err(RpcError::Rpc {
+                code: error.code,
+                message: error.message,
+                data: error.data,
+            })
+        } else if let Some(result) = parsed.result {
+            Ok(result)
+        } else {
+            Err(RpcError::InvalidResponse(
+                "Response has neither result nor error".to_string(),
+            ))
+        };
+
+        match entry {
+            PendingEntry::Call(sender) => {
+                let _ = sender.send(result);
+            }
+            PendingEntry::Subscribe(resp_sender, sub_sender) => {
+                if let Ok(value) = &result {
+                    if let Some(subscription_id) = value.as_u64() {
+                        subscriptions.lock().unwrap().insert(subscription_id, sub_sender);
+                    }
+                    // Non-numeric result: subscribe() below reports
+                    // InvalidResponse; sub_sender is simply dropped unused.
+                }
+                let _ = resp_sender.send(result);
+            }
         }
     }
 
-    pub fn connect(&self, stdin: ChildStdin, stdout: ChildStdout) {
-        *self.stdin.lock().unwrap() = Some(stdin);
-        *self.stdout.lock().unwrap() = Some(BufReader::new(stdout));
+    fn fail_all_pending(pending: &PendingMap, error: RpcError) {
+        let mut map = pending.lock().unwrap();
+        for (_, entry) in map.drain() {
+            let err = match &error {
+                RpcError::NotConnected => RpcError::NotConnected,
+                other => RpcError::InvalidResponse(other.to_string()),
+            };
+            match entry {
+                PendingEntry::Call(sender) => {
+                    let _ = sender.send(Err(err));
+                }
+                PendingEntry::Subscribe(resp_sender, _) => {
+                    let _ = resp_sender.send(Err(err));
+                }
+            }
+        }
+    }
+
+    /// Route a notification (no `id`) to the subscriber named by its
+    /// `params.subscription` field, if any subscription is still open.
+    fn dispatch_notification(
+        subscriptions: &SubscriptionMap,
+        method: Option<String>,
+        params: Option<Value>,
+    ) {
+        let Some(params) = params else {
+            log::debug!("Ignoring notification with no params: {:?}", method);
+            return;
+        };
+
+        let Some(subscription_id) = params.get("subscription").and_then(Value::as_u64) else {
+            log::debug!("Ignoring notification with no subscription id: {:?}", method);
+            return;
+        };
+
+        let sender = subscriptions.lock().unwrap().get(&subscription_id).cloned();
+        match sender {
+            Some(sender) => {
+                let _ = sender.send(params);
+            }
+            None => {
+                log::debug!(
+                    "Dropping notification for unknown/closed subscription {}",
+                    subscription_id
+                );
+            }
+        }
     }
 
     pub fn disconnect(&self) {
-        *self.stdin.lock().unwrap() = None;
-        *self.stdout.lock().unwrap() = None;
+        *self.writer.lock().unwrap() = None;
+        self.connected.store(false, Ordering::SeqCst);
+        Self::fail_all_pending(&self.pending, RpcError::NotConnected);
+        self.subscriptions.lock().unwrap().clear();
+        if let Some(handle) = self.reader_handle.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+        *self.handshake.lock().unwrap() = None;
     }
 
     pub fn is_connected(&self) -> bool {
-        self.stdin.lock().unwrap().is_some()
+        self.connected.load(Ordering::SeqCst)
     }
 
+    /// Call an RPC method, waiting up to the default timeout for a response.
     pub fn call(&self, method: &str, params: Option<Value>) -> Result<Value, RpcError> {
-        let id = self.request_id.fetch_add(1, Ordering::SeqCst);
-        let request = RpcRequest::new(method, params, id);
+        self.call_timeout(method, params, DEFAULT_CALL_TIMEOUT)
+    }
 
-        // Send request
-        {
-            let mut stdin_guard = self.stdin.lock().unwrap();
-            let stdin = stdin_guard.as_mut().ok_or(RpcError::NotConnected)?;
+    /// Call an RPC method, waiting up to `timeout` for a response.
+    ///
+    /// On expiry the pending entry is removed so a late response is simply
+    /// dropped by `dispatch_line` rather than delivered to a stale waiter.
+    pub fn call_timeout(
+        &self,
+        method: &str,
+        params: Option<Value>,
+        timeout: Duration,
+    ) -> Result<Value, RpcError> {
+        self.call_timeout_tracked(method, params, timeout, |_| {})
+    }
 
-            let request_json = serde_json::to_string(&request)?;
-            log::debug!("RPC request: {}", request_json);
+    /// Like `call`, but invokes `on_request_id` with the request id assigned
+    /// to this call before blocking on the response. Callers that want to
+    /// cancel a call in flight (e.g. a "kill stuck call" diagnostic) need
+    /// that id to pass to `cancel`.
+    pub fn call_tracked<F: FnOnce(u64)>(
+        &self,
+        method: &str,
+        params: Option<Value>,
+        on_request_id: F,
+    ) -> Result<Value, RpcError> {
+        self.call_timeout_tracked(method, params, DEFAULT_CALL_TIMEOUT, on_request_id)
+    }
 
-            writeln!(stdin, "{}", request_json)?;
-            stdin.flush()?;
+    /// Like `call_timeout`, but invokes `on_request_id` with the assigned
+    /// request id before blocking on the response.
+    pub fn call_timeout_tracked<F: FnOnce(u64)>(
+        &self,
+        method: &str,
+        params: Option<Value>,
+        timeout: Duration,
+        on_request_id: F,
+    ) -> Result<Value, RpcError> {
+        let (tx, rx) = mpsc::channel();
+        let id = self.send_request(method, params, PendingEntry::Call(tx))?;
+        on_request_id(id);
+        Self::wait_for_response(&self.pending, id, method, rx, timeout)
+    }
+
+    /// Abandon a pending call: removes its pending entry (so a late response
+    /// is dropped rather than delivered) and wakes the blocked waiter with
+    /// `RpcError::Cancelled`. Returns `false` if `id` is not (or is no
+    /// longer) pending.
+    pub fn cancel(&self, id: u64) -> bool {
+        match self.pending.lock().unwrap().remove(&id) {
+            Some(PendingEntry::Call(sender)) => {
+                let _ = sender.send(Err(RpcError::Cancelled));
+                true
+            }
+            Some(PendingEntry::Subscribe(resp_sender, _)) => {
+                let _ = resp_sender.send(Err(RpcError::Cancelled));
+                true
+            }
+            None => false,
         }
+    }
+
+    /// Open a subscription: call `method` to start it, then return the
+    /// subscription id the backend assigned along with a receiver that
+    /// yields each `params` payload of a matching `{"method": ..., "params":
+    /// {"subscription": id, ...}}` notification as it arrives.
+    ///
+    /// The receiver closes (further `recv` calls return `Err`) once
+    /// `unsubscribe` is called for this id or the client disconnects.
+    pub fn subscribe(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<(u64, mpsc::Receiver<Value>), RpcError> {
+        let (resp_tx, resp_rx) = mpsc::channel();
+        let (sub_tx, sub_rx) = mpsc::channel();
+        let id = self.send_request(
+            method,
+            params,
+            PendingEntry::Subscribe(resp_tx, sub_tx),
+        )?;
+
+        let result = Self::wait_for_response(
+            &self.pending,
+            id,
+            method,
+            resp_rx,
+            DEFAULT_CALL_TIMEOUT,
+        )?;
+        let subscription_id = result.as_u64().ok_or_else(|| {
+            RpcError::InvalidResponse(format!(
+                "Subscribe result is not a subscription id: {}",
+                result
+            ))
+        })?;
+
+        Ok((subscription_id, sub_rx))
+    }
 
-        // Read response
-        {
-            let mut stdout_guard = self.stdout.lock().unwrap();
-            let stdout = stdout_guard.as_mut().ok_or(RpcError::NotConnected)?;
+    /// Close a subscription opened with `subscribe`, calling `method` to tell
+    /// the backend to stop pushing notifications for it.
+    pub fn unsubscribe(&self, method: &str, subscription_id: u64) -> Result<(), RpcError> {
+        self.subscriptions.lock().unwrap().remove(&subscription_id);
+        self.call(method, Some(serde_json::json!({ "subscription": subscription_id })))?;
+        Ok(())
+    }
 
-            let mut response_line = String::new();
-            stdout.read_line(&mut response_line)?;
+    /// Send every `(method, params)` pair as one JSON-RPC batch request (a
+    /// single line containing a JSON array), waiting up to the default
+    /// timeout for each slot's response.
+    ///
+    /// Unlike `call`, this never returns an outer `Err`: a failure that would
+    /// otherwise prevent the whole batch from being sent (not connected, a
+    /// write error) is instead reported in every slot, so a caller can always
+    /// zip the result back up against the services/calls it asked for.
+    pub fn call_batch(&self, calls: Vec<(&str, Option<Value>)>) -> Vec<Result<Value, RpcError>> {
+        self.call_batch_timeout(calls, DEFAULT_CALL_TIMEOUT)
+    }
 
-            log::debug!("RPC response: {}", response_line.trim());
+    /// Like `call_batch`, but waits up to `timeout` for each slot.
+    pub fn call_batch_timeout(
+        &self,
+        calls: Vec<(&str, Option<Value>)>,
+        timeout: Duration,
+    ) -> Vec<Result<Value, RpcError>> {
+        if calls.is_empty() {
+            return Vec::new();
+        }
 
-            let response: RpcResponse = serde_json::from_str(&response_line)?;
+        if !self.is_connected() {
+            return calls.iter().map(|_| Err(RpcError::NotConnected)).collect();
+        }
+
+        let mut ids = Vec::with_capacity(calls.len());
+        let mut receivers = Vec::with_capacity(calls.len());
+        let mut requests = Vec::with_capacity(calls.len());
 
-            // Check for error
-            if let Some(error) = response.error {
-                return Err(RpcError::Rpc {
-                    code: error.code,
-                    message: error.message,
-                    data: error.data,
-                });
+        for &(method, ref params) in &calls {
+            let id = self.request_id.fetch_add(1, Ordering::SeqCst);
+            let (tx, rx) = mpsc::channel();
+            self.pending.lock().unwrap().insert(id, PendingEntry::Call(tx));
+            requests.push(RpcRequest::new(method, params.clone(), id));
+            ids.push(id);
+            receivers.push(rx);
+        }
+
+        let request_json = match serde_json::to_string(&requests) {
+            Ok(json) => json,
+            Err(e) => {
+                let mut pending = self.pending.lock().unwrap();
+                for id in &ids {
+                    pending.remove(id);
+                }
+                drop(pending);
+                let message = e.to_string();
+                return ids
+                    .iter()
+                    .map(|_| Err(RpcError::InvalidResponse(message.clone())))
+                    .collect();
             }
+        };
 
-            // Verify response ID matches
-            if response.id != Some(id) {
-                return Err(RpcError::InvalidResponse(format!(
-                    "Response ID {} doesn't match request ID {}",
-                    response.id.unwrap_or(0),
-                    id
-                )));
+        let mut writer_guard = self.writer.lock().unwrap();
+        let writer = match writer_guard.as_mut() {
+            Some(w) => w,
+            None => {
+                drop(writer_guard);
+                let mut pending = self.pending.lock().unwrap();
+                for id in &ids {
+                    pending.remove(id);
+                }
+                return ids.iter().map(|_| Err(RpcError::NotConnected)).collect();
             }
+        };
 
-            response.result.ok_or_else(|| {
-                RpcError::InvalidResponse("Response has neither result nor error".to_string())
-            })
+        log::debug!("RPC batch request (ids={:?}): {}", ids, request_json);
+
+        if let Err(e) = writeln!(writer, "{}", request_json).and_then(|_| writer.flush()) {
+            drop(writer_guard);
+            let mut pending = self.pending.lock().unwrap();
+            for id in &ids {
+                pending.remove(id);
+            }
+            drop(pending);
+            let message = e.to_string();
+            return ids
+                .iter()
+                .map(|_| Err(RpcError::Io(io::Error::new(e.kind(), message.clone()))))
+                .collect();
+        }
+        drop(writer_guard);
+
+        ids.into_iter()
+            .zip(calls)
+            .zip(receivers)
+            .map(|((id, (method, _)), rx)| Self::wait_for_response(&self.pending, id, method, rx, timeout))
+            .collect()
+    }
+
+    /// Allocate a request id, register `entry` as the pending handler for it,
+    /// and write the request line to the transport.
+    fn send_request(
+        &self,
+        method: &str,
+        params: Option<Value>,
+        entry: PendingEntry,
+    ) -> Result<u64, RpcError> {
+        if !self.is_connected() {
+            return Err(RpcError::NotConnected);
+        }
+
+        let id = self.request_id.fetch_add(1, Ordering::SeqCst);
+        let request = RpcRequest::new(method, params, id);
+
+        self.pending.lock().unwrap().insert(id, entry);
+
+        let mut writer_guard = self.writer.lock().unwrap();
+        let writer = match writer_guard.as_mut() {
+            Some(w) => w,
+            None => {
+                self.pending.lock().unwrap().remove(&id);
+                return Err(RpcError::NotConnected);
+            }
+        };
+
+        let request_json = serde_json::to_string(&request)?;
+        log::debug!("RPC request (id={}): {}", id, request_json);
+
+        if let Err(e) = writeln!(writer, "{}", request_json).and_then(|_| writer.flush()) {
+            drop(writer_guard);
+            self.pending.lock().unwrap().remove(&id);
+            return Err(RpcError::Io(e));
+        }
+
+        Ok(id)
+    }
+
+    /// Block on `rx` for the response to request `id`, removing its pending
+    /// entry on expiry so a late response is simply dropped by
+    /// `dispatch_line` instead of delivered to a stale waiter.
+    fn wait_for_response(
+        pending: &PendingMap,
+        id: u64,
+        method: &str,
+        rx: mpsc::Receiver<Result<Value, RpcError>>,
+        timeout: Duration,
+    ) -> Result<Value, RpcError> {
+        match rx.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(_) => {
+                pending.lock().unwrap().remove(&id);
+                log::warn!(
+                    "RPC call {} (id={}) timed out after {:?}",
+                    method,
+                    id,
+                    timeout
+                );
+                Err(RpcError::Timeout {
+                    method: method.to_string(),
+                    elapsed: timeout,
+                })
+            }
         }
     }
 }
@@ -150,6 +730,12 @@ impl Default for RpcClient {
     }
 }
 
+impl Drop for RpcClient {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +826,13 @@ mod tests {
 
         let not_connected = RpcError::NotConnected;
         assert!(not_connected.to_string().contains("not connected"));
+
+        let timeout = RpcError::Timeout {
+            method: "deploy.deploy".to_string(),
+            elapsed: Duration::from_secs(30),
+        };
+        assert!(timeout.to_string().contains("deploy.deploy"));
+        assert!(timeout.to_string().contains("timed out"));
     }
 
     #[test]
@@ -249,4 +842,256 @@ mod tests {
         let id2 = client.request_id.fetch_add(1, Ordering::SeqCst);
         assert_eq!(id2, id1 + 1);
     }
+
+    #[test]
+    fn test_dispatch_line_routes_by_id() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel();
+        pending.lock().unwrap().insert(7, PendingEntry::Call(tx));
+
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"{"jsonrpc":"2.0","result":{"ok":true},"id":7}"#,
+        );
+
+        let result = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(result.unwrap(), serde_json::json!({"ok": true}));
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_line_ignores_notification_with_no_subscriber() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+        // Should not panic even though there is no `id` and nothing subscribed.
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"{"jsonrpc":"2.0","method":"deploy.logs.data","params":{"line":"hi"}}"#,
+        );
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_line_routes_notification_to_subscription() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel();
+        subscriptions.lock().unwrap().insert(42, tx);
+
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"{"jsonrpc":"2.0","method":"deploy.logs.data","params":{"subscription":42,"line":"building..."}}"#,
+        );
+
+        let params = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(params["line"], "building...");
+    }
+
+    #[test]
+    fn test_dispatch_line_drops_notification_for_closed_subscription() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+
+        // No subscriber registered for id 99 — should not panic.
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"{"jsonrpc":"2.0","method":"deploy.logs.data","params":{"subscription":99,"line":"late"}}"#,
+        );
+    }
+
+    #[test]
+    fn test_fail_all_pending_delivers_not_connected() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel();
+        pending.lock().unwrap().insert(1, PendingEntry::Call(tx));
+
+        RpcClient::fail_all_pending(&pending, RpcError::NotConnected);
+
+        match rx.recv_timeout(Duration::from_secs(1)).unwrap() {
+            Err(RpcError::NotConnected) => (),
+            other => panic!("Expected NotConnected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_line_registers_subscription_before_replying() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+        let (resp_tx, resp_rx) = mpsc::channel();
+        let (sub_tx, sub_rx) = mpsc::channel();
+        pending
+            .lock()
+            .unwrap()
+            .insert(3, PendingEntry::Subscribe(resp_tx, sub_tx));
+
+        // The subscribe confirmation carries the new subscription id as its result.
+        RpcClient::dispatch_line(&pending, &subscriptions, r#"{"jsonrpc":"2.0","result":42,"id":3}"#);
+
+        // Registration must already be visible once the caller's response arrives,
+        // so a notification delivered immediately after can never be dropped.
+        assert_eq!(resp_rx.recv_timeout(Duration::from_secs(1)).unwrap().unwrap(), 42);
+        assert!(subscriptions.lock().unwrap().contains_key(&42));
+
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"{"jsonrpc":"2.0","method":"deploy.logs.data","params":{"subscription":42,"line":"first"}}"#,
+        );
+        assert_eq!(
+            sub_rx.recv_timeout(Duration::from_secs(1)).unwrap()["line"],
+            "first"
+        );
+    }
+
+    #[test]
+    fn test_parse_hello_stores_version_and_methods() {
+        let result = serde_json::json!({
+            "version": PROTOCOL_VERSION,
+            "methods": ["deploy.deploy", "infra.status"]
+        });
+        let handshake = parse_hello(&result).unwrap();
+        assert_eq!(handshake.version, PROTOCOL_VERSION);
+        assert!(handshake.methods.contains("deploy.deploy"));
+        assert!(handshake.methods.contains("infra.status"));
+        assert!(!handshake.methods.contains("deploy.rollback"));
+    }
+
+    #[test]
+    fn test_parse_hello_rejects_incompatible_major_version() {
+        let result = serde_json::json!({ "version": PROTOCOL_VERSION + 100, "methods": [] });
+        match parse_hello(&result) {
+            Err(RpcError::IncompatibleVersion { client, server }) => {
+                assert_eq!(client, PROTOCOL_VERSION);
+                assert_eq!(server, PROTOCOL_VERSION + 100);
+            }
+            other => panic!("Expected IncompatibleVersion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_hello_allows_higher_minor_version() {
+        let result = serde_json::json!({ "version": PROTOCOL_VERSION + 1, "methods": [] });
+        assert!(parse_hello(&result).is_ok());
+    }
+
+    #[test]
+    fn test_parse_hello_missing_version_is_invalid_response() {
+        let result = serde_json::json!({ "methods": [] });
+        match parse_hello(&result) {
+            Err(RpcError::InvalidResponse(_)) => (),
+            other => panic!("Expected InvalidResponse, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_supports_false_before_handshake() {
+        let client = RpcClient::new();
+        assert!(!client.supports("deploy.deploy"));
+        assert_eq!(client.negotiated_version(), None);
+    }
+
+    #[test]
+    fn test_wait_for_response_times_out_and_clears_pending_entry() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel::<Result<Value, RpcError>>();
+        pending.lock().unwrap().insert(5, PendingEntry::Call(tx));
+
+        let result = RpcClient::wait_for_response(
+            &pending,
+            5,
+            "deploy.deploy",
+            rx,
+            Duration::from_millis(50),
+        );
+
+        match result {
+            Err(RpcError::Timeout { method, elapsed }) => {
+                assert_eq!(method, "deploy.deploy");
+                assert_eq!(elapsed, Duration::from_millis(50));
+            }
+            other => panic!("Expected Timeout, got {:?}", other),
+        }
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_call_checked_rejects_unadvertised_method() {
+        let client = RpcClient::new();
+        match client.call_checked("deploy.rollback", None) {
+            Err(RpcError::UnsupportedMethod(method)) => assert_eq!(method, "deploy.rollback"),
+            other => panic!("Expected UnsupportedMethod, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_call_batch_empty_is_empty() {
+        let client = RpcClient::new();
+        assert!(client.call_batch(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_call_batch_not_connected_fails_every_slot() {
+        let client = RpcClient::new();
+        let results = client.call_batch(vec![("deploy.deploy", None), ("deploy.status", None)]);
+        assert_eq!(results.len(), 2);
+        for result in results {
+            assert!(matches!(result, Err(RpcError::NotConnected)));
+        }
+    }
+
+    #[test]
+    fn test_dispatch_line_routes_batch_array_by_id() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        pending.lock().unwrap().insert(1, PendingEntry::Call(tx1));
+        pending.lock().unwrap().insert(2, PendingEntry::Call(tx2));
+
+        // Responses are deliberately out of order, per the JSON-RPC batch spec.
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"[{"jsonrpc":"2.0","result":{"service":"b"},"id":2},{"jsonrpc":"2.0","result":{"service":"a"},"id":1}]"#,
+        );
+
+        assert_eq!(
+            rx1.recv_timeout(Duration::from_secs(1)).unwrap().unwrap(),
+            serde_json::json!({"service": "a"})
+        );
+        assert_eq!(
+            rx2.recv_timeout(Duration::from_secs(1)).unwrap().unwrap(),
+            serde_json::json!({"service": "b"})
+        );
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_line_batch_level_error_fails_all_pending() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let subscriptions: SubscriptionMap = Mutex::new(HashMap::new());
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        pending.lock().unwrap().insert(1, PendingEntry::Call(tx1));
+        pending.lock().unwrap().insert(2, PendingEntry::Call(tx2));
+
+        RpcClient::dispatch_line(
+            &pending,
+            &subscriptions,
+            r#"{"jsonrpc":"2.0","error":{"code":-32600,"message":"Invalid Request"},"id":null}"#,
+        );
+
+        for rx in [rx1, rx2] {
+            match rx.recv_timeout(Duration::from_secs(1)).unwrap() {
+                Err(RpcError::InvalidResponse(_)) => (),
+                other => panic!("Expected InvalidResponse, got {:?}", other),
+            }
+        }
+        assert!(pending.lock().unwrap().is_empty());
+    }
 }
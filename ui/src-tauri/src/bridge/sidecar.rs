@@ -1,11 +1,28 @@
 use super::rpc::{RpcClient, RpcError};
-use super::tcp::{TcpRpcClient, TcpRpcError};
+use super::tcp::{TcpConnectionPool, TcpRpcClient, TcpRpcError};
+use serde::Serialize;
 use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, ChildStderr, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
+/// Line the subprocess bridge prints on stdout-ready startup. `start_subprocess`
+/// blocks on seeing this (or the timeout) before attempting `system.ping`, so
+/// a backend that fails to come up surfaces its actual traceback instead of a
+/// generic "Ping failed".
+const READY_MARKER: &str = "DEVFLOW_READY";
+/// How long `start_subprocess` waits for `READY_MARKER` before giving up.
+const READY_TIMEOUT: Duration = Duration::from_secs(15);
+/// How many trailing stderr lines to keep around for error messages.
+const STDERR_TAIL_LINES: usize = 20;
+
 /// Errors that can occur during bridge operations.
 #[derive(Error, Debug)]
 pub enum BridgeError {
@@ -26,6 +43,167 @@ pub enum BridgeError {
 
     #[error("Invalid connection mode configuration")]
     InvalidConfig,
+
+    #[error("Unknown stream token: {0}")]
+    UnknownStream(String),
+
+    #[error("Unknown in-flight call id: {0}")]
+    UnknownCall(u64),
+
+    #[error("bridge too old, method unsupported: {0}")]
+    UnsupportedMethod(String),
+
+    #[error("Backend protocol version {server} is incompatible with client version {client}")]
+    IncompatibleVersion { client: u32, server: u32 },
+}
+
+/// A live pubsub subscription handed out as an opaque token, so commands can
+/// expose a frontend-facing handle (e.g. for an event channel name) instead
+/// of the bridge-level numeric subscription id.
+struct StreamHandle {
+    unsubscribe_method: String,
+    subscription_id: u64,
+}
+
+/// A live interactive exec session: a bidirectional stream of stdout/stderr
+/// chunks plus the bridge-level subscription id the backend uses to route
+/// `stdin`/`resize`/`close` calls to the right container process.
+struct ExecSession {
+    subscription_id: u64,
+    service: String,
+    command: Vec<String>,
+    tty: bool,
+}
+
+/// A `setup_dev` run in progress: the steps streamed so far (as raw
+/// notification payloads, so `BridgeManager` stays agnostic of the
+/// `SetupStep` shape), so a reconnecting UI can fetch where it left off.
+struct SetupRun {
+    subscription_id: u64,
+    steps: Vec<Value>,
+    finished: bool,
+}
+
+/// An interactive exec session, as surfaced for enumeration.
+#[derive(Clone, Debug, Serialize)]
+pub struct ExecSessionInfo {
+    pub session_id: String,
+    pub service: String,
+    pub command: Vec<String>,
+    pub tty: bool,
+}
+
+/// Supervised connection health, as surfaced to the UI so it can show an
+/// offline banner and the reconnect progress.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConnectionStatus {
+    pub mode: String,
+    pub up: bool,
+    pub uptime_ms: Option<u64>,
+    pub last_error: Option<String>,
+    pub reconnect_attempts: u64,
+    /// Coarse health summary for a status badge: "Connected", "Degraded"
+    /// (down, backoff in progress), "Reconnecting" (restart/redial actively
+    /// underway), or "Down" (no attempt made yet).
+    pub health_state: String,
+}
+
+/// Coarse health state derived from `health_up`/`reconnecting`/
+/// `reconnect_attempts`, for `ConnectionStatus::health_state`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HealthState {
+    Connected,
+    Degraded,
+    Reconnecting,
+    Down,
+}
+
+/// Aggregated invocation stats for one RPC method, snapshotted from atomic
+/// counters so reading them never contends with the hot call path.
+#[derive(Clone, Debug, Serialize)]
+pub struct MethodStats {
+    pub method: String,
+    pub call_count: u64,
+    pub avg_duration_ms: f64,
+}
+
+/// A currently in-flight RPC call, as surfaced to a "connection inspector" UI.
+#[derive(Clone, Debug, Serialize)]
+pub struct BridgeConnection {
+    pub call_id: u64,
+    pub method: String,
+    pub args_summary: String,
+    pub started_at_ms: u64,
+    pub elapsed_ms: u64,
+}
+
+/// Per-method call count and total duration, updated with atomics so
+/// recording a call never takes a lock on the hot path.
+struct MethodCounters {
+    count: AtomicU64,
+    total_micros: AtomicU64,
+}
+
+impl MethodCounters {
+    fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            total_micros: AtomicU64::new(0),
+        }
+    }
+
+    fn record(&self, duration: Duration) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> (u64, f64) {
+        let count = self.count.load(Ordering::Relaxed);
+        let total_micros = self.total_micros.load(Ordering::Relaxed);
+        let avg_ms = if count == 0 {
+            0.0
+        } else {
+            (total_micros as f64 / count as f64) / 1000.0
+        };
+        (count, avg_ms)
+    }
+}
+
+/// Bookkeeping for a call currently awaiting a response.
+struct InFlightCall {
+    method: String,
+    args_summary: String,
+    started_at: Instant,
+    started_at_ms: u64,
+    /// The underlying `RpcClient` request id, if this call was placed over
+    /// `rpc_client` (subprocess/socket mode) and can therefore be cancelled.
+    /// TCP calls have no cancellation handle today.
+    rpc_request_id: Option<u64>,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Render `params` as a short, human-readable summary for the connection
+/// inspector, without risking a multi-kilobyte payload in the UI.
+fn summarize_args(params: &Option<Value>) -> String {
+    match params {
+        Some(value) => {
+            let rendered = value.to_string();
+            let truncated: String = rendered.chars().take(200).collect();
+            if truncated.len() < rendered.len() {
+                format!("{}…", truncated)
+            } else {
+                truncated
+            }
+        }
+        None => "null".to_string(),
+    }
 }
 
 /// State of the bridge connection.
@@ -44,6 +222,15 @@ pub enum ConnectionMode {
     Subprocess,
     /// TCP mode connecting to a remote service (Windows -> WSL2)
     Tcp,
+    /// A local, portless transport to a co-located bridge: a Unix domain
+    /// socket on Unix, a named pipe on Windows. Preferred over TCP when
+    /// available; `start` falls back to TCP if the socket/pipe is missing.
+    Socket,
+    /// A remote bridge reached over an SSH local port-forward: `start` shells
+    /// out to `ssh -N -L` to tunnel a local port to the DevFlow service on
+    /// `SshConfig::host`, then drives it exactly like `Tcp` mode against
+    /// `127.0.0.1:local_port`.
+    Ssh,
 }
 
 impl Default for ConnectionMode {
@@ -70,22 +257,46 @@ impl ConnectionMode {
 pub struct TcpConfig {
     pub host: String,
     pub port: u16,
+    /// Extra pooled connections to open alongside the primary `tcp_client`
+    /// (which stays dedicated to health pings), so concurrent Tauri
+    /// commands don't serialize behind one socket. `1` means no pool.
+    pub pool_size: u32,
 }
 
 impl Default for TcpConfig {
     fn default() -> Self {
         Self {
-            host: "127.0.0.1".to_string(),
+            host: get_wsl2_host(),
             port: 9876,
+            pool_size: 1,
         }
     }
 }
 
+/// SSH local port-forward configuration for `ConnectionMode::Ssh`: mirrors
+/// the `ssh_user`/`ssh_key_secret`/`host` fields `DeploymentEnvConfig` and
+/// `DatabaseEnvConfig` already carry for remote deploys/migrations.
+#[derive(Clone, Debug)]
+pub struct SshConfig {
+    pub host: String,
+    pub user: String,
+    pub identity_file: Option<String>,
+    /// Port the DevFlow service listens on on the remote host.
+    pub remote_port: u16,
+    /// Local port to forward to `remote_port`, which `tcp_client` then
+    /// connects to as if it were a local service.
+    pub local_port: u16,
+}
+
 /// Manages the bridge connection to the Python backend.
 ///
-/// Supports two connection modes:
+/// Supports four connection modes:
 /// - Subprocess: Spawns Python process with stdio communication (Linux/macOS)
 /// - TCP: Connects to a running DevFlow service via TCP (Windows -> WSL2)
+/// - Socket: Connects to a bridge listening on a Unix domain socket, e.g. one
+///   left running inside a container
+/// - Ssh: Opens an `ssh -N -L` port-forward to a remote DevFlow service and
+///   connects to it over TCP through the forwarded local port
 pub struct BridgeManager {
     state: Mutex<BridgeState>,
     mode: Mutex<ConnectionMode>,
@@ -93,9 +304,55 @@ pub struct BridgeManager {
     process: Mutex<Option<Child>>,
     rpc_client: Arc<RpcClient>,
     python_path: Mutex<Option<PathBuf>>,
+    // Trailing lines captured from the subprocess's stderr, for surfacing the
+    // backend's own error output when startup or a probe fails. `Arc`-wrapped
+    // so the reader thread spawned in `start_subprocess` can hold a handle
+    // independent of `&self`'s lifetime.
+    stderr_tail: Arc<Mutex<VecDeque<String>>>,
     // TCP mode fields
     tcp_client: Arc<TcpRpcClient>,
     tcp_config: Mutex<Option<TcpConfig>>,
+    // Extra pooled connections for concurrent calls beyond `tcp_client`;
+    // `None` when `TcpConfig::pool_size` is 1 (the common case).
+    tcp_pool: Mutex<Option<Arc<TcpConnectionPool>>>,
+    // Socket mode fields
+    socket_path: Mutex<Option<PathBuf>>,
+    // SSH mode fields: `ssh_tunnel` reuses `tcp_client` above once the
+    // forward is up, so it only needs the forwarding child process tracked.
+    ssh_config: Mutex<Option<SshConfig>>,
+    ssh_tunnel: Mutex<Option<Child>>,
+    // Token-keyed registry of live streaming subscriptions (dev log tails,
+    // status follows, etc.), so multiple streams can run concurrently and be
+    // cancelled individually by the token handed back to the frontend.
+    stream_registry: Mutex<HashMap<String, StreamHandle>>,
+    next_stream_id: AtomicU64,
+    // RPC diagnostics: per-method invocation counters and the set of calls
+    // currently awaiting a response, for a "connection inspector" UI.
+    method_stats: Mutex<HashMap<String, Arc<MethodCounters>>>,
+    in_flight: Mutex<HashMap<u64, InFlightCall>>,
+    next_call_id: AtomicU64,
+    // Live interactive exec sessions, keyed by the session id handed back to
+    // the frontend, so they can be enumerated and force-closed.
+    exec_sessions: Mutex<HashMap<String, ExecSession>>,
+    next_exec_session_id: AtomicU64,
+    // In-progress `setup_dev` runs, keyed by the token handed to the
+    // frontend, so a reconnecting UI can re-attach and fetch steps
+    // completed so far instead of losing all progress.
+    setup_runs: Mutex<HashMap<String, SetupRun>>,
+    next_setup_run_id: AtomicU64,
+    // Connection resilience: the args `start` was last called with (so the
+    // health supervisor can reconnect without needing them re-supplied), plus
+    // the supervised up/down status.
+    last_start_args: Mutex<Option<(String, Option<String>)>>,
+    health_up: AtomicBool,
+    health_monitor_running: AtomicBool,
+    reconnect_attempts: AtomicU64,
+    up_since: Mutex<Option<Instant>>,
+    last_error: Mutex<Option<String>>,
+    /// Set while the health supervisor is actively mid-attempt (restarting a
+    /// container, redialing, re-pinging) rather than just idly waiting for
+    /// the next probe.
+    reconnecting: AtomicBool,
 }
 
 impl BridgeManager {
@@ -107,8 +364,29 @@ impl BridgeManager {
             process: Mutex::new(None),
             rpc_client: Arc::new(RpcClient::new()),
             python_path: Mutex::new(None),
+            stderr_tail: Arc::new(Mutex::new(VecDeque::new())),
             tcp_client: Arc::new(TcpRpcClient::new()),
             tcp_config: Mutex::new(None),
+            tcp_pool: Mutex::new(None),
+            socket_path: Mutex::new(None),
+            ssh_config: Mutex::new(None),
+            ssh_tunnel: Mutex::new(None),
+            stream_registry: Mutex::new(HashMap::new()),
+            next_stream_id: AtomicU64::new(1),
+            method_stats: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+            next_call_id: AtomicU64::new(1),
+            exec_sessions: Mutex::new(HashMap::new()),
+            next_exec_session_id: AtomicU64::new(1),
+            last_start_args: Mutex::new(None),
+            health_up: AtomicBool::new(false),
+            health_monitor_running: AtomicBool::new(false),
+            reconnect_attempts: AtomicU64::new(0),
+            up_since: Mutex::new(None),
+            last_error: Mutex::new(None),
+            reconnecting: AtomicBool::new(false),
+            setup_runs: Mutex::new(HashMap::new()),
+            next_setup_run_id: AtomicU64::new(1),
         }
     }
 
@@ -120,8 +398,29 @@ impl BridgeManager {
             process: Mutex::new(None),
             rpc_client: Arc::new(RpcClient::new()),
             python_path: Mutex::new(None),
+            stderr_tail: Arc::new(Mutex::new(VecDeque::new())),
             tcp_client: Arc::new(TcpRpcClient::new()),
             tcp_config: Mutex::new(None),
+            tcp_pool: Mutex::new(None),
+            socket_path: Mutex::new(None),
+            ssh_config: Mutex::new(None),
+            ssh_tunnel: Mutex::new(None),
+            stream_registry: Mutex::new(HashMap::new()),
+            next_stream_id: AtomicU64::new(1),
+            method_stats: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+            next_call_id: AtomicU64::new(1),
+            exec_sessions: Mutex::new(HashMap::new()),
+            next_exec_session_id: AtomicU64::new(1),
+            last_start_args: Mutex::new(None),
+            health_up: AtomicBool::new(false),
+            health_monitor_running: AtomicBool::new(false),
+            reconnect_attempts: AtomicU64::new(0),
+            up_since: Mutex::new(None),
+            last_error: Mutex::new(None),
+            reconnecting: AtomicBool::new(false),
+            setup_runs: Mutex::new(HashMap::new()),
+            next_setup_run_id: AtomicU64::new(1),
         }
     }
 
@@ -135,9 +434,25 @@ impl BridgeManager {
         *self.mode.lock().unwrap()
     }
 
-    /// Set TCP configuration for TCP mode.
+    /// Set TCP configuration for TCP mode, with no connection pooling.
     pub fn set_tcp_config(&self, host: String, port: u16) {
-        *self.tcp_config.lock().unwrap() = Some(TcpConfig { host, port });
+        self.set_tcp_config_pooled(host, port, 1);
+    }
+
+    /// Set TCP configuration for TCP mode with `pool_size` pooled connections
+    /// (see `BackendConfig::effective_pool_size`) for concurrent RPC calls.
+    pub fn set_tcp_config_pooled(&self, host: String, port: u16, pool_size: u32) {
+        *self.tcp_config.lock().unwrap() = Some(TcpConfig { host, port, pool_size });
+    }
+
+    /// Set the socket path (Unix) or pipe name (Windows) for Socket mode.
+    pub fn set_socket_path(&self, path: impl Into<PathBuf>) {
+        *self.socket_path.lock().unwrap() = Some(path.into());
+    }
+
+    /// Set the SSH tunnel configuration for Ssh mode.
+    pub fn set_ssh_config(&self, config: SshConfig) {
+        *self.ssh_config.lock().unwrap() = Some(config);
     }
 
     /// Set the Python executable path for subprocess mode.
@@ -154,15 +469,92 @@ impl BridgeManager {
     ///
     /// For subprocess mode, spawns the Python process.
     /// For TCP mode, connects to the running service.
+    /// For socket mode, falls back to TCP if the socket/named pipe is
+    /// unavailable, since that usually just means the sidecar wasn't started
+    /// with one co-located.
     pub fn start(&self, bridge_module: &str, working_dir: Option<&str>) -> Result<(), BridgeError> {
+        *self.last_start_args.lock().unwrap() =
+            Some((bridge_module.to_string(), working_dir.map(|s| s.to_string())));
+
         let mode = *self.mode.lock().unwrap();
 
         match mode {
             ConnectionMode::Subprocess => self.start_subprocess(bridge_module, working_dir),
             ConnectionMode::Tcp => self.start_tcp(),
+            ConnectionMode::Socket => self.start_socket().or_else(|e| {
+                log::warn!("Socket/named-pipe connection unavailable ({}), falling back to TCP", e);
+                self.set_mode(ConnectionMode::Tcp);
+                self.start_tcp()
+            }),
+            ConnectionMode::Ssh => self.start_ssh(),
         }
     }
 
+    /// Re-run `start` with the arguments it was last called with, tearing
+    /// down any existing connection first. Used by the health supervisor to
+    /// recover from a dropped connection.
+    pub fn reconnect(&self) -> Result<(), BridgeError> {
+        let args = self.last_start_args.lock().unwrap().clone();
+        let (bridge_module, working_dir) = args.ok_or(BridgeError::NotRunning)?;
+        self.stop();
+        self.start(&bridge_module, working_dir.as_deref())
+    }
+
+    /// Spawn a thread that drains `stderr` for the life of the process: every
+    /// line is logged (as `error!` if it looks like a traceback/error, `warn!`
+    /// otherwise) and appended to `tail`, trimmed to `STDERR_TAIL_LINES`. The
+    /// returned receiver fires once, the first time a line containing
+    /// `READY_MARKER` is seen.
+    fn spawn_stderr_reader(
+        tail: Arc<Mutex<VecDeque<String>>>,
+        stderr: ChildStderr,
+    ) -> mpsc::Receiver<()> {
+        let (ready_tx, ready_rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut ready_tx = Some(ready_tx);
+            for line in BufReader::new(stderr).lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(_) => break,
+                };
+
+                if line.contains(READY_MARKER) {
+                    if let Some(tx) = ready_tx.take() {
+                        let _ = tx.send(());
+                    }
+                }
+
+                let lowercase = line.to_lowercase();
+                if lowercase.contains("error") || lowercase.contains("traceback") {
+                    log::error!("[bridge stderr] {}", line);
+                } else {
+                    log::warn!("[bridge stderr] {}", line);
+                }
+
+                let mut tail = tail.lock().unwrap();
+                tail.push_back(line);
+                while tail.len() > STDERR_TAIL_LINES {
+                    tail.pop_front();
+                }
+            }
+        });
+
+        ready_rx
+    }
+
+    /// The captured stderr tail, joined into a single string for embedding in
+    /// a `BridgeError::StartFailed` message.
+    fn captured_stderr_tail(&self) -> String {
+        self.stderr_tail
+            .lock()
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Start the bridge in subprocess mode.
     fn start_subprocess(
         &self,
@@ -217,6 +609,28 @@ impl BridgeManager {
             .spawn()
             .map_err(|e| BridgeError::StartFailed(format!("Failed to spawn process: {}", e)))?;
 
+        // Drain stderr on a dedicated thread for the life of the process, so
+        // a chatty backend can't fill the pipe buffer and deadlock, and so
+        // its output ends up in our logs. Also watch for `READY_MARKER` so we
+        // can block startup on it below instead of racing `system.ping`
+        // against a backend that hasn't finished initializing yet.
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| BridgeError::StartFailed("Failed to get stderr".to_string()))?;
+        let ready_rx = Self::spawn_stderr_reader(Arc::clone(&self.stderr_tail), stderr);
+
+        if ready_rx.recv_timeout(READY_TIMEOUT).is_err() {
+            let tail = self.captured_stderr_tail();
+            let _ = child.kill();
+            let _ = child.wait();
+            *self.state.lock().unwrap() = BridgeState::Error;
+            return Err(BridgeError::StartFailed(format!(
+                "Bridge did not report ready within {:?}; stderr:\n{}",
+                READY_TIMEOUT, tail
+            )));
+        }
+
         // Get stdin/stdout handles
         let stdin = child
             .stdin
@@ -228,7 +642,17 @@ impl BridgeManager {
             .ok_or_else(|| BridgeError::StartFailed("Failed to get stdout".to_string()))?;
 
         // Connect RPC client
-        self.rpc_client.connect(stdin, stdout);
+        self.rpc_client.connect(stdin, stdout).map_err(|e| {
+            match e.get_ref().and_then(|inner| inner.downcast_ref::<RpcError>()) {
+                Some(RpcError::IncompatibleVersion { client, server }) => {
+                    BridgeError::IncompatibleVersion {
+                        client: *client,
+                        server: *server,
+                    }
+                }
+                _ => BridgeError::StartFailed(format!("Failed to connect RPC client: {}", e)),
+            }
+        })?;
 
         // Store process
         *self.process.lock().unwrap() = Some(child);
@@ -273,7 +697,26 @@ impl BridgeManager {
         // Connect TCP client
         self.tcp_client
             .connect(&tcp_config.host, tcp_config.port)
-            .map_err(|e| BridgeError::StartFailed(format!("TCP connection failed: {}", e)))?;
+            .map_err(|e| match e {
+                TcpRpcError::ProtocolMismatch { client, server } => {
+                    BridgeError::IncompatibleVersion { client, server }
+                }
+                e => BridgeError::StartFailed(format!("TCP connection failed: {}", e)),
+            })?;
+
+        // Open the extra pooled connections `tcp_client` itself doesn't
+        // cover, if this backend asked for more than one.
+        *self.tcp_pool.lock().unwrap() = if tcp_config.pool_size > 1 {
+            match TcpConnectionPool::connect(&tcp_config.host, tcp_config.port, tcp_config.pool_size as usize) {
+                Ok(pool) => Some(Arc::new(pool)),
+                Err(e) => {
+                    log::warn!("Failed to open TCP connection pool ({}), falling back to a single connection", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
 
         // Test connection with ping
         match self.tcp_client.ping() {
@@ -291,21 +734,232 @@ impl BridgeManager {
         }
     }
 
+    /// Start the bridge in SSH-tunnel mode: shells out to `ssh -N -L` to
+    /// forward a local port to the DevFlow service on the remote host, waits
+    /// for the forward to come up, then drives it exactly like `start_tcp`
+    /// against `127.0.0.1:local_port`.
+    fn start_ssh(&self) -> Result<(), BridgeError> {
+        let mut state = self.state.lock().unwrap();
+        if *state == BridgeState::Running {
+            return Ok(());
+        }
+
+        *state = BridgeState::Starting;
+        drop(state);
+
+        let ssh_config = self
+            .ssh_config
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(BridgeError::InvalidConfig)?;
+
+        log::info!(
+            "Opening SSH tunnel to {}@{} ({} -> 127.0.0.1:{})",
+            ssh_config.user,
+            ssh_config.host,
+            ssh_config.local_port,
+            ssh_config.remote_port
+        );
+
+        let mut cmd = Command::new("ssh");
+        cmd.arg("-N")
+            .arg("-L")
+            .arg(format!(
+                "{}:127.0.0.1:{}",
+                ssh_config.local_port, ssh_config.remote_port
+            ))
+            .arg(format!("{}@{}", ssh_config.user, ssh_config.host))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped());
+
+        if let Some(identity_file) = &ssh_config.identity_file {
+            cmd.arg("-i").arg(identity_file);
+        }
+
+        let child = cmd
+            .spawn()
+            .map_err(|e| BridgeError::StartFailed(format!("Failed to spawn ssh: {}", e)))?;
+
+        *self.ssh_tunnel.lock().unwrap() = Some(child);
+
+        if let Err(e) = self.wait_for_port("127.0.0.1", ssh_config.local_port, Duration::from_secs(10)) {
+            self.kill_ssh_tunnel();
+            *self.state.lock().unwrap() = BridgeState::Error;
+            return Err(BridgeError::StartFailed(format!(
+                "SSH tunnel did not come up: {}",
+                e
+            )));
+        }
+
+        // Connect TCP client against the forwarded local port.
+        self.tcp_client
+            .connect("127.0.0.1", ssh_config.local_port)
+            .map_err(|e| match e {
+                TcpRpcError::ProtocolMismatch { client, server } => {
+                    BridgeError::IncompatibleVersion { client, server }
+                }
+                e => BridgeError::StartFailed(format!("TCP connection over SSH tunnel failed: {}", e)),
+            })?;
+
+        match self.tcp_client.ping() {
+            Ok(result) => {
+                log::info!("Bridge connected (SSH tunnel), ping response: {:?}", result);
+                *self.state.lock().unwrap() = BridgeState::Running;
+                Ok(())
+            }
+            Err(e) => {
+                log::error!("Bridge ping failed: {}", e);
+                self.tcp_client.disconnect();
+                self.kill_ssh_tunnel();
+                *self.state.lock().unwrap() = BridgeState::Error;
+                Err(BridgeError::StartFailed(format!("Ping failed: {}", e)))
+            }
+        }
+    }
+
+    /// Poll `host:port` until a TCP connection succeeds or `timeout` elapses.
+    fn wait_for_port(&self, host: &str, port: u16, timeout: Duration) -> Result<(), std::io::Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match std::net::TcpStream::connect((host, port)) {
+                Ok(_) => return Ok(()),
+                Err(e) => {
+                    if Instant::now() >= deadline {
+                        return Err(e);
+                    }
+                    std::thread::sleep(Duration::from_millis(200));
+                }
+            }
+        }
+    }
+
+    /// Kill and reap the SSH port-forward child, if one is running.
+    fn kill_ssh_tunnel(&self) {
+        if let Some(mut child) = self.ssh_tunnel.lock().unwrap().take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    /// Start the bridge in Unix domain socket mode.
+    #[cfg(unix)]
+    fn start_socket(&self) -> Result<(), BridgeError> {
+        let mut state = self.state.lock().unwrap();
+        if *state == BridgeState::Running {
+            return Ok(());
+        }
+
+        *state = BridgeState::Starting;
+        drop(state);
+
+        let path = self
+            .socket_path
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(BridgeError::InvalidConfig)?;
+
+        log::info!("Connecting to DevFlow bridge over socket {}", path.display());
+
+        let transport = super::transport::UnixSocketTransport::connect(&path)?;
+        self.rpc_client
+            .connect_transport(Box::new(transport))
+            .map_err(|e| BridgeError::StartFailed(format!("Socket connection failed: {}", e)))?;
+
+        match self.rpc_client.call("system.ping", None) {
+            Ok(result) => {
+                log::info!("Bridge connected (socket), ping response: {:?}", result);
+                *self.state.lock().unwrap() = BridgeState::Running;
+                Ok(())
+            }
+            Err(e) => {
+                log::error!("Bridge ping failed: {}", e);
+                self.rpc_client.disconnect();
+                *self.state.lock().unwrap() = BridgeState::Error;
+                Err(BridgeError::StartFailed(format!("Ping failed: {}", e)))
+            }
+        }
+    }
+
+    /// Start the bridge over a Windows named pipe. `socket_path` doubles as
+    /// the pipe name here (e.g. "devflow-bridge"), rather than a filesystem
+    /// path, since named pipes live in their own `\\.\pipe\` namespace.
+    #[cfg(windows)]
+    fn start_socket(&self) -> Result<(), BridgeError> {
+        let mut state = self.state.lock().unwrap();
+        if *state == BridgeState::Running {
+            return Ok(());
+        }
+
+        *state = BridgeState::Starting;
+        drop(state);
+
+        let pipe_name = self
+            .socket_path
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(BridgeError::InvalidConfig)?
+            .to_string_lossy()
+            .to_string();
+
+        log::info!("Connecting to DevFlow bridge over named pipe {}", pipe_name);
+
+        let transport = super::transport::NamedPipeTransport::connect(&pipe_name)?;
+        self.rpc_client
+            .connect_transport(Box::new(transport))
+            .map_err(|e| BridgeError::StartFailed(format!("Named pipe connection failed: {}", e)))?;
+
+        match self.rpc_client.call("system.ping", None) {
+            Ok(result) => {
+                log::info!("Bridge connected (named pipe), ping response: {:?}", result);
+                *self.state.lock().unwrap() = BridgeState::Running;
+                Ok(())
+            }
+            Err(e) => {
+                log::error!("Bridge ping failed: {}", e);
+                self.rpc_client.disconnect();
+                *self.state.lock().unwrap() = BridgeState::Error;
+                Err(BridgeError::StartFailed(format!("Ping failed: {}", e)))
+            }
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn start_socket(&self) -> Result<(), BridgeError> {
+        Err(BridgeError::InvalidConfig)
+    }
+
     /// Stop the bridge connection.
     pub fn stop(&self) {
         let mode = *self.mode.lock().unwrap();
 
         match mode {
             ConnectionMode::Subprocess => {
-                self.rpc_client.disconnect();
-
+                // Kill the child before disconnecting: disconnect() joins the
+                // RPC reader thread, which is blocked reading the child's
+                // stdout and won't see EOF until the process actually exits.
                 if let Some(mut child) = self.process.lock().unwrap().take() {
                     let _ = child.kill();
                     let _ = child.wait();
                 }
+
+                self.rpc_client.disconnect();
             }
             ConnectionMode::Tcp => {
                 self.tcp_client.disconnect();
+                if let Some(pool) = self.tcp_pool.lock().unwrap().take() {
+                    pool.disconnect_all();
+                }
+            }
+            ConnectionMode::Socket => {
+                self.rpc_client.disconnect();
+            }
+            ConnectionMode::Ssh => {
+                self.tcp_client.disconnect();
+                self.kill_ssh_tunnel();
             }
         }
 
@@ -319,20 +973,61 @@ impl BridgeManager {
             return Err(BridgeError::NotRunning);
         }
 
+        let call_id = self.next_call_id.fetch_add(1, Ordering::Relaxed);
+        let args_summary = summarize_args(&params);
+        let started_at = Instant::now();
+        let started_at_ms = now_millis();
+
         let mode = *self.mode.lock().unwrap();
 
-        match mode {
-            ConnectionMode::Subprocess => {
-                self.rpc_client.call(method, params).map_err(|e| {
-                    // Check if the bridge died
-                    if matches!(e, RpcError::Io(_) | RpcError::NotConnected) {
-                        *self.state.lock().unwrap() = BridgeState::Error;
-                    }
-                    BridgeError::Rpc(e)
-                })
+        let result = match mode {
+            ConnectionMode::Subprocess | ConnectionMode::Socket => {
+                let in_flight = &self.in_flight;
+                let method_owned = method.to_string();
+                let args_owned = args_summary.clone();
+                self.rpc_client
+                    .call_tracked(method, params, |rpc_request_id| {
+                        in_flight.lock().unwrap().insert(
+                            call_id,
+                            InFlightCall {
+                                method: method_owned,
+                                args_summary: args_owned,
+                                started_at,
+                                started_at_ms,
+                                rpc_request_id: Some(rpc_request_id),
+                            },
+                        );
+                    })
+                    .map_err(|e| {
+                        // Check if the bridge died
+                        if matches!(e, RpcError::Io(_) | RpcError::NotConnected) {
+                            *self.state.lock().unwrap() = BridgeState::Error;
+                        }
+                        BridgeError::Rpc(e)
+                    })
             }
-            ConnectionMode::Tcp => {
-                self.tcp_client.call(method, params).map_err(|e| {
+            ConnectionMode::Tcp | ConnectionMode::Ssh => {
+                // TCP (and SSH-tunneled TCP) calls have no cancellation
+                // handle, but are still tracked for the connection inspector.
+                self.in_flight.lock().unwrap().insert(
+                    call_id,
+                    InFlightCall {
+                        method: method.to_string(),
+                        args_summary: args_summary.clone(),
+                        started_at,
+                        started_at_ms,
+                        rpc_request_id: None,
+                    },
+                );
+                // Route through the pool when one is open so concurrent
+                // calls don't serialize behind `tcp_client`, which stays
+                // reserved for health pings.
+                let pool = self.tcp_pool.lock().unwrap().clone();
+                let result = match pool {
+                    Some(pool) => pool.get().and_then(|conn| conn.call(method, params)),
+                    None => self.tcp_client.call(method, params),
+                };
+                result.map_err(|e| {
                     // Check if the connection died
                     if matches!(e, TcpRpcError::Io(_) | TcpRpcError::NotConnected) {
                         *self.state.lock().unwrap() = BridgeState::Error;
@@ -340,9 +1035,295 @@ impl BridgeManager {
                     BridgeError::TcpRpc(e)
                 })
             }
+        };
+
+        self.in_flight.lock().unwrap().remove(&call_id);
+
+        let counters = self
+            .method_stats
+            .lock()
+            .unwrap()
+            .entry(method.to_string())
+            .or_insert_with(|| Arc::new(MethodCounters::new()))
+            .clone();
+        counters.record(started_at.elapsed());
+
+        result
+    }
+
+    /// Send a batch of `(method, params)` calls as one round trip instead of
+    /// N sequential ones (e.g. deploying a whole stack of services). Only
+    /// subprocess/socket mode support batching today; TCP (and SSH-tunneled
+    /// TCP) mode reports `BridgeError::InvalidConfig` for every slot.
+    pub fn call_batch(&self, calls: Vec<(&str, Option<Value>)>) -> Vec<Result<Value, BridgeError>> {
+        if self.get_state() != BridgeState::Running {
+            return calls.iter().map(|_| Err(BridgeError::NotRunning)).collect();
+        }
+
+        match *self.mode.lock().unwrap() {
+            ConnectionMode::Subprocess | ConnectionMode::Socket => self
+                .rpc_client
+                .call_batch(calls)
+                .into_iter()
+                .map(|r| r.map_err(BridgeError::Rpc))
+                .collect(),
+            ConnectionMode::Tcp | ConnectionMode::Ssh => {
+                calls.iter().map(|_| Err(BridgeError::InvalidConfig)).collect()
+            }
+        }
+    }
+
+    /// Whether the connected backend advertised `method` during its
+    /// handshake (`rpc.hello` for subprocess/socket, `system.version` for
+    /// TCP and SSH-tunneled TCP).
+    pub fn supports(&self, method: &str) -> bool {
+        match *self.mode.lock().unwrap() {
+            ConnectionMode::Subprocess | ConnectionMode::Socket => self.rpc_client.supports(method),
+            ConnectionMode::Tcp | ConnectionMode::Ssh => self.tcp_client.supports(method),
+        }
+    }
+
+    /// The protocol version the connected backend reported during its
+    /// handshake, if one has completed.
+    pub fn negotiated_version(&self) -> Option<u32> {
+        match *self.mode.lock().unwrap() {
+            ConnectionMode::Subprocess | ConnectionMode::Socket => self.rpc_client.negotiated_version(),
+            ConnectionMode::Tcp | ConnectionMode::Ssh => self.tcp_client.negotiated_version(),
+        }
+    }
+
+    /// Like `call`, but first checks the handshake-advertised method set and
+    /// fails fast with `BridgeError::UnsupportedMethod` instead of letting an
+    /// unsupported call surface as a confusing error partway through.
+    pub fn call_checked(&self, method: &str, params: Option<Value>) -> Result<Value, BridgeError> {
+        if !self.supports(method) {
+            return Err(BridgeError::UnsupportedMethod(method.to_string()));
+        }
+        self.call(method, params)
+    }
+
+    /// Aggregated per-method invocation counts and average durations over
+    /// every recorded call.
+    pub fn stats(&self) -> Vec<MethodStats> {
+        self.method_stats
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(method, counters)| {
+                let (call_count, avg_duration_ms) = counters.snapshot();
+                MethodStats {
+                    method: method.clone(),
+                    call_count,
+                    avg_duration_ms,
+                }
+            })
+            .collect()
+    }
+
+    /// Calls currently awaiting a response, for a connection inspector UI.
+    pub fn connections(&self) -> Vec<BridgeConnection> {
+        self.in_flight
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(call_id, call)| BridgeConnection {
+                call_id: *call_id,
+                method: call.method.clone(),
+                args_summary: call.args_summary.clone(),
+                started_at_ms: call.started_at_ms,
+                elapsed_ms: call.started_at.elapsed().as_millis() as u64,
+            })
+            .collect()
+    }
+
+    /// Abandon a stuck in-flight call: forgets its bookkeeping and, in
+    /// subprocess/socket mode, wakes the blocked caller with
+    /// `RpcError::Cancelled` so its slot is freed immediately. TCP calls
+    /// have no cancellation handle, so they're only forgotten here; the
+    /// blocked caller still waits for the transport to time out.
+    pub fn kill_call(&self, call_id: u64) -> Result<(), BridgeError> {
+        let call = self
+            .in_flight
+            .lock()
+            .unwrap()
+            .remove(&call_id)
+            .ok_or(BridgeError::UnknownCall(call_id))?;
+
+        if let Some(rpc_request_id) = call.rpc_request_id {
+            self.rpc_client.cancel(rpc_request_id);
+        }
+
+        Ok(())
+    }
+
+    /// Open a pubsub subscription on the bridge. Supported in subprocess and
+    /// socket mode, both of which share `rpc_client`; TCP pubsub is not
+    /// implemented yet.
+    pub fn subscribe(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<(u64, std::sync::mpsc::Receiver<Value>), BridgeError> {
+        if self.get_state() != BridgeState::Running {
+            return Err(BridgeError::NotRunning);
+        }
+
+        match *self.mode.lock().unwrap() {
+            ConnectionMode::Subprocess | ConnectionMode::Socket => {
+                self.rpc_client.subscribe(method, params).map_err(|e| {
+                    if matches!(e, RpcError::Io(_) | RpcError::NotConnected) {
+                        *self.state.lock().unwrap() = BridgeState::Error;
+                    }
+                    BridgeError::Rpc(e)
+                })
+            }
+            ConnectionMode::Tcp | ConnectionMode::Ssh => Err(BridgeError::InvalidConfig),
+        }
+    }
+
+    /// Close a subscription opened with `subscribe`.
+    pub fn unsubscribe(&self, method: &str, subscription_id: u64) -> Result<(), BridgeError> {
+        match *self.mode.lock().unwrap() {
+            ConnectionMode::Subprocess | ConnectionMode::Socket => self
+                .rpc_client
+                .unsubscribe(method, subscription_id)
+                .map_err(BridgeError::Rpc),
+            ConnectionMode::Tcp | ConnectionMode::Ssh => Err(BridgeError::InvalidConfig),
+        }
+    }
+
+    /// Register a live subscription under a fresh opaque token and return it,
+    /// so a command can hand the token to the frontend as an event-channel
+    /// suffix while keeping the bridge-level `subscription_id` private.
+    pub fn register_stream(&self, unsubscribe_method: impl Into<String>, subscription_id: u64) -> String {
+        let token = format!("strm-{}", self.next_stream_id.fetch_add(1, Ordering::Relaxed));
+        self.stream_registry.lock().unwrap().insert(
+            token.clone(),
+            StreamHandle {
+                unsubscribe_method: unsubscribe_method.into(),
+                subscription_id,
+            },
+        );
+        token
+    }
+
+    /// Cancel a stream previously registered with `register_stream`: closes
+    /// the underlying subscription and forgets the token.
+    pub fn cancel_stream(&self, token: &str) -> Result<(), BridgeError> {
+        let handle = self
+            .stream_registry
+            .lock()
+            .unwrap()
+            .remove(token)
+            .ok_or_else(|| BridgeError::UnknownStream(token.to_string()))?;
+        self.unsubscribe(&handle.unsubscribe_method, handle.subscription_id)
+    }
+
+    /// Register a newly-opened interactive exec session (a `subscribe`d
+    /// stdout/stderr stream) under a fresh session id.
+    pub fn register_exec_session(
+        &self,
+        subscription_id: u64,
+        service: String,
+        command: Vec<String>,
+        tty: bool,
+    ) -> String {
+        let session_id = format!("exec-{}", self.next_exec_session_id.fetch_add(1, Ordering::Relaxed));
+        self.exec_sessions.lock().unwrap().insert(
+            session_id.clone(),
+            ExecSession {
+                subscription_id,
+                service,
+                command,
+                tty,
+            },
+        );
+        session_id
+    }
+
+    /// The bridge-level subscription id backing `session_id`, which is what
+    /// the backend expects as the `session` param on `stdin`/`resize` calls
+    /// (`close_exec_session` sends it as `subscription` via `unsubscribe`). `None` if the session is unknown or already closed.
+    pub fn exec_session_subscription_id(&self, session_id: &str) -> Option<u64> {
+        self.exec_sessions
+            .lock()
+            .unwrap()
+            .get(session_id)
+            .map(|session| session.subscription_id)
+    }
+
+    /// List live interactive exec sessions.
+    pub fn list_exec_sessions(&self) -> Vec<ExecSessionInfo> {
+        self.exec_sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(session_id, session)| ExecSessionInfo {
+                session_id: session_id.clone(),
+                service: session.service.clone(),
+                command: session.command.clone(),
+                tty: session.tty,
+            })
+            .collect()
+    }
+
+    /// Force-close an interactive exec session, telling the backend to
+    /// terminate the underlying container process.
+    pub fn close_exec_session(&self, session_id: &str) -> Result<(), BridgeError> {
+        let session = self
+            .exec_sessions
+            .lock()
+            .unwrap()
+            .remove(session_id)
+            .ok_or_else(|| BridgeError::UnknownStream(session_id.to_string()))?;
+        self.unsubscribe("dev.exec_interactive.close", session.subscription_id)
+    }
+
+    /// Register a newly-started `setup_dev` run under a fresh token.
+    pub fn register_setup_run(&self, subscription_id: u64) -> String {
+        let token = format!("setup-{}", self.next_setup_run_id.fetch_add(1, Ordering::Relaxed));
+        self.setup_runs.lock().unwrap().insert(
+            token.clone(),
+            SetupRun {
+                subscription_id,
+                steps: Vec::new(),
+                finished: false,
+            },
+        );
+        token
+    }
+
+    /// Record a `SetupStep` notification (or the final summary) against its
+    /// run, so a reconnecting UI can fetch progress made so far.
+    pub fn record_setup_step(&self, token: &str, step: Value, finished: bool) {
+        if let Some(run) = self.setup_runs.lock().unwrap().get_mut(token) {
+            run.steps.push(step);
+            run.finished = run.finished || finished;
         }
     }
 
+    /// Steps completed so far for an in-progress or finished setup run, for
+    /// an attaching UI to catch up on. `None` if the token is unknown.
+    pub fn setup_run_steps(&self, token: &str) -> Option<(Vec<Value>, bool)> {
+        self.setup_runs
+            .lock()
+            .unwrap()
+            .get(token)
+            .map(|run| (run.steps.clone(), run.finished))
+    }
+
+    /// Cancel an in-progress `setup_dev` run, telling the backend to abort
+    /// and forgetting the token.
+    pub fn cancel_setup_run(&self, token: &str) -> Result<(), BridgeError> {
+        let run = self
+            .setup_runs
+            .lock()
+            .unwrap()
+            .remove(token)
+            .ok_or_else(|| BridgeError::UnknownStream(token.to_string()))?;
+        self.unsubscribe("dev.setup.cancel", run.subscription_id)
+    }
+
     /// Get the subprocess RPC client (for advanced use).
     pub fn rpc_client(&self) -> Arc<RpcClient> {
         Arc::clone(&self.rpc_client)
@@ -362,6 +1343,111 @@ impl BridgeManager {
     pub fn is_subprocess_mode(&self) -> bool {
         *self.mode.lock().unwrap() == ConnectionMode::Subprocess
     }
+
+    /// Check if using Unix domain socket mode.
+    pub fn is_socket_mode(&self) -> bool {
+        *self.mode.lock().unwrap() == ConnectionMode::Socket
+    }
+
+    /// Current supervised connection health, for `get_bridge_connection_status`
+    /// and `get_backend_health`.
+    pub fn connection_status(&self) -> ConnectionStatus {
+        let up = self.health_up.load(Ordering::Relaxed);
+        let uptime_ms = if up {
+            self.up_since.lock().unwrap().map(|since| since.elapsed().as_millis() as u64)
+        } else {
+            None
+        };
+
+        ConnectionStatus {
+            mode: format!("{:?}", self.get_mode()),
+            up,
+            uptime_ms,
+            last_error: self.last_error.lock().unwrap().clone(),
+            reconnect_attempts: self.reconnect_attempts.load(Ordering::Relaxed),
+            health_state: format!("{:?}", self.health_state()),
+        }
+    }
+
+    fn health_state(&self) -> HealthState {
+        if self.reconnecting.load(Ordering::Relaxed) {
+            HealthState::Reconnecting
+        } else if self.health_up.load(Ordering::Relaxed) {
+            HealthState::Connected
+        } else if self.reconnect_attempts.load(Ordering::Relaxed) == 0 {
+            HealthState::Down
+        } else {
+            HealthState::Degraded
+        }
+    }
+
+    /// Mark whether the health supervisor is actively mid-attempt (restarting
+    /// a container, redialing, re-pinging) versus idling in backoff. Used to
+    /// distinguish `Reconnecting` from `Degraded` in `connection_status`.
+    pub fn set_reconnecting(&self, reconnecting: bool) {
+        self.reconnecting.store(reconnecting, Ordering::Relaxed);
+    }
+
+    /// Record the result of a health probe. Returns `true` if this call
+    /// changed the up/down status (a "transition"), which is when the caller
+    /// should emit a `bridge:status` event.
+    pub fn mark_health(&self, up: bool, error: Option<String>) -> bool {
+        let was_up = self.health_up.swap(up, Ordering::SeqCst);
+        if up && !was_up {
+            *self.up_since.lock().unwrap() = Some(Instant::now());
+        }
+        *self.last_error.lock().unwrap() = if up { None } else { error };
+        was_up != up
+    }
+
+    /// Record a reconnect attempt, returning the new attempt count.
+    pub fn record_reconnect_attempt(&self) -> u64 {
+        self.reconnect_attempts.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Reset the reconnect attempt counter after a successful reconnect.
+    pub fn reset_reconnect_attempts(&self) {
+        self.reconnect_attempts.store(0, Ordering::Relaxed);
+    }
+
+    /// Non-blocking reap of the subprocess child, for when it self-terminates
+    /// (crash, OOM kill, `rm`'d Python env) instead of being stopped via
+    /// `stop()`. Uses `try_wait` rather than `wait` so it's safe to call from
+    /// the health monitor's probe loop without blocking it. Returns `true` if
+    /// a dead child was found and reaped.
+    pub fn reap_subprocess(&self) -> bool {
+        if *self.mode.lock().unwrap() != ConnectionMode::Subprocess {
+            return false;
+        }
+
+        let mut process = self.process.lock().unwrap();
+        let Some(child) = process.as_mut() else {
+            return false;
+        };
+
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                log::warn!("Bridge subprocess exited on its own ({}), reaping", status);
+                *process = None;
+                drop(process);
+                *self.state.lock().unwrap() = BridgeState::Error;
+                true
+            }
+            Ok(None) => false,
+            Err(e) => {
+                log::warn!("Failed to poll bridge subprocess status: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Claim the right to run the health monitor loop. Returns `false` if
+    /// one is already running, so callers don't spawn a second supervisor.
+    pub fn try_start_health_monitor(&self) -> bool {
+        self.health_monitor_running
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
 }
 
 impl Default for BridgeManager {
@@ -376,15 +1462,100 @@ impl Drop for BridgeManager {
     }
 }
 
-/// Get the default WSL2 host address.
+/// Detect the address the Windows host should use to reach the WSL2 VM,
+/// caching the result on first use.
 ///
-/// On Windows, WSL2 is accessible via localhost when using
-/// the newer WSL2 networking mode.
+/// Under WSL2 mirrored networking the VM shares the host's network namespace and `127.0.0.1` works directly. But
+/// under the default NAT networking mode, Windows must instead reach WSL2 by
+/// its assigned VM IP: on Windows that's queried with `wsl.exe hostname -I`;
+/// from inside WSL it's the nameserver line in `/etc/resolv.conf` (the NAT
+/// gateway back to the host). Falls back to `127.0.0.1` if detection fails or
+/// the nameserver is already loopback (mirrored mode).
 pub fn get_wsl2_host() -> String {
-    "127.0.0.1".to_string()
+    static CACHED_HOST: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+    CACHED_HOST.get_or_init(detect_wsl2_host).clone()
+}
+
+fn detect_wsl2_host() -> String {
+    let detected = if cfg!(windows) {
+        wsl2_host_via_wsl_exe()
+    } else if running_inside_wsl() {
+        wsl2_host_via_resolv_conf()
+    } else {
+        None
+    };
+    detected.unwrap_or_else(|| "127.0.0.1".to_string())
+}
+
+/// Whether this process is itself running inside WSL, per the kernel's own
+/// `microsoft`/`WSL` marker in its release string. An ordinary Linux or macOS
+/// host has no NAT gateway to go looking for in `/etc/resolv.conf` - without
+/// this check, `wsl2_host_via_resolv_conf` would instead return that host's
+/// regular DNS resolver (router, corporate resolver) as the "WSL2 host".
+fn running_inside_wsl() -> bool {
+    std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .map(|release| {
+            let release = release.to_lowercase();
+            release.contains("microsoft") || release.contains("wsl")
+        })
+        .unwrap_or(false)
+}
+
+/// Whether `ip` is in `127.0.0.0/8`, not just the literal `127.0.0.1` -
+/// systemd-resolved hosts report `127.0.0.53` as the nameserver, which is
+/// just as much loopback (and just as useless as a "WSL2 host" address).
+fn is_loopback(ip: &str) -> bool {
+    ip.parse::<std::net::Ipv4Addr>()
+        .map(|addr| addr.is_loopback())
+        .unwrap_or(false)
+}
+
+/// Ask `wsl.exe` for the WSL2 VM's IP address (Windows side, NAT mode).
+fn wsl2_host_via_wsl_exe() -> Option<String> {
+    let output = Command::new("wsl.exe").arg("hostname").arg("-I").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let ip = stdout.split_whitespace().next()?.to_string();
+    if ip.is_empty() || is_loopback(&ip) {
+        None
+    } else {
+        Some(ip)
+    }
+}
+
+/// Parse the nameserver from `/etc/resolv.conf` (WSL side, NAT mode): that
+/// address is the NAT gateway back to the Windows host. Returns `None` (so
+/// the caller falls back to `127.0.0.1`) if the nameserver is already
+/// loopback, which indicates mirrored networking is active.
+fn wsl2_host_via_resolv_conf() -> Option<String> {
+    let contents = std::fs::read_to_string("/etc/resolv.conf").ok()?;
+    contents.lines().find_map(|line| {
+        let ip = line.trim().strip_prefix("nameserver")?.trim();
+        if ip.is_empty() || is_loopback(ip) {
+            None
+        } else {
+            Some(ip.to_string())
+        }
+    })
 }
 
 /// Get the default DevFlow service port.
 pub fn get_default_port() -> u16 {
     9876
 }
+
+#[cfg(test)]
+mod wsl2_host_tests {
+    use super::is_loopback;
+
+    #[test]
+    fn test_is_loopback_covers_127_0_0_0_8() {
+        assert!(is_loopback("127.0.0.1"));
+        assert!(is_loopback("127.0.0.53")); // systemd-resolved's stub resolver
+        assert!(is_loopback("127.1.2.3"));
+        assert!(!is_loopback("192.168.1.1"));
+        assert!(!is_loopback("not-an-ip"));
+    }
+}
@@ -1,12 +1,19 @@
+use super::transport::{Transport, TcpTransport};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::io::{BufRead, BufReader, Write};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, Write};
 use std::net::TcpStream;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::Duration;
 use thiserror::Error;
 
+#[cfg(windows)]
+use super::transport::NamedPipeTransport;
+
 /// Error types for TCP RPC communication.
 #[derive(Error, Debug)]
 pub enum TcpRpcError {
@@ -34,6 +41,9 @@ pub enum TcpRpcError {
 
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
+
+    #[error("Backend protocol version {server} is incompatible with client version {client}")]
+    ProtocolMismatch { client: u32, server: u32 },
 }
 
 /// JSON-RPC 2.0 request structure.
@@ -56,14 +66,18 @@ impl RpcRequest {
     }
 }
 
-/// JSON-RPC 2.0 response structure.
+/// A line received off the background reader: either a response to a pending
+/// `call` (carries an `id`) or a server-pushed notification (no `id`, has a
+/// `method`).
 #[derive(Debug, Deserialize)]
-struct RpcResponse {
+struct RpcLine {
     #[allow(dead_code)]
-    jsonrpc: String,
+    jsonrpc: Option<String>,
+    method: Option<String>,
     result: Option<Value>,
     error: Option<RpcErrorObject>,
     id: Option<u64>,
+    params: Option<Value>,
 }
 
 /// JSON-RPC 2.0 error object.
@@ -74,42 +88,163 @@ struct RpcErrorObject {
     data: Option<Value>,
 }
 
+type PendingMap = Mutex<HashMap<u64, Sender<Result<Value, TcpRpcError>>>>;
+type NotificationMap = Mutex<HashMap<String, Vec<Sender<Value>>>>;
+
+/// This client's protocol version, checked against the `protocol` field of
+/// `system.version` during the handshake every `connect`/`connect_pipe`
+/// performs. A mismatch fails the connect with `TcpRpcError::ProtocolMismatch`
+/// instead of letting mismatched method shapes fail cryptically later.
+pub const SUPPORTED_PROTOCOL: u32 = 1;
+
+/// How a previously-established connection should be re-established: which
+/// `connect*` method to call and with what arguments. Stored on successful
+/// connect so the reconnect policy can redial without the caller supplying
+/// the address again.
+#[derive(Clone, Debug)]
+enum ConnectTarget {
+    Tcp { host: String, port: u16 },
+    #[cfg(windows)]
+    Pipe { name: String },
+}
+
+/// Reconnect policy: how many times to redial after a dead connection is
+/// detected mid-`call`, and the delay after the first failed redial (doubled
+/// after each further failed redial). The first redial is made at once.
+#[derive(Clone, Copy, Debug)]
+struct ReconnectPolicy {
+    max_retries: u32,
+    base_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_backoff: Duration::from_millis(200),
+        }
+    }
+}
+
+/// The negotiated outcome of the `system.version` handshake: the backend's
+/// protocol version and the set of methods/capabilities it advertised, so
+/// `supports` can answer without a round trip.
+#[derive(Debug, Clone, Default)]
+struct TcpHandshake {
+    version: u32,
+    methods: std::collections::HashSet<String>,
+}
+
+/// Parse a `system.version` result and check its `protocol` field against
+/// `SUPPORTED_PROTOCOL`. A backend that doesn't report a `protocol` field is
+/// assumed compatible (older backends predating this handshake), so this
+/// only rejects an explicit, known-incompatible mismatch rather than every
+/// backend lacking the field. The `methods` field (if present) is collected
+/// as the capability set `supports` checks against.
+fn parse_handshake(result: &Value) -> Result<TcpHandshake, TcpRpcError> {
+    let version = result
+        .get("protocol")
+        .and_then(Value::as_u64)
+        .map(|v| v as u32)
+        .unwrap_or(SUPPORTED_PROTOCOL);
+
+    if version != SUPPORTED_PROTOCOL {
+        return Err(TcpRpcError::ProtocolMismatch {
+            client: SUPPORTED_PROTOCOL,
+            server: version,
+        });
+    }
+
+    let methods = result
+        .get("methods")
+        .and_then(Value::as_array)
+        .map(|methods| {
+            methods
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(TcpHandshake { version, methods })
+}
+
 /// TCP RPC client for connecting to DevFlow service.
 ///
-/// This client is used on Windows to communicate with the Python
-/// backend running in WSL2 via TCP instead of stdio.
+/// Despite the name, this isn't TCP-only: it runs over any [`Transport`], so
+/// a Windows client talking to a co-located bridge can use `connect_pipe` to
+/// go over a named pipe instead of opening a loopback TCP port (and the
+/// firewall prompt that comes with it). `connect` remains the TCP path used
+/// to reach the Python backend running in WSL2.
+///
+/// A background thread spawned by `connect`/`connect_pipe` owns the reader
+/// half of the transport and reads every line as soon as it arrives, routing
+/// it to whichever `call` is waiting on that request id via a
+/// `Mutex<HashMap<u64, Sender<...>>>` of pending requests. This lets several
+/// callers (e.g. concurrent Tauri commands sharing one connection) have
+/// calls in flight at once instead of each one locking the reader and
+/// assuming the very next line is its own response. Lines with no `id` are
+/// notifications and are dispatched to `subscribe`rs of their `method`
+/// regardless of whether a call is in flight.
 pub struct TcpRpcClient {
-    stream: Mutex<Option<TcpStream>>,
-    reader: Mutex<Option<BufReader<TcpStream>>>,
+    writer: Mutex<Option<Box<dyn Write + Send>>>,
+    connected: Arc<AtomicBool>,
     request_id: AtomicU64,
     connect_timeout: Duration,
     read_timeout: Duration,
+    pending: Arc<PendingMap>,
+    notifications: Arc<NotificationMap>,
+    reader_handle: Mutex<Option<thread::JoinHandle<()>>>,
+    last_target: Mutex<Option<ConnectTarget>>,
+    reconnect_policy: Mutex<ReconnectPolicy>,
+    handshake: Mutex<Option<TcpHandshake>>,
 }
 
 impl TcpRpcClient {
     /// Create a new TCP RPC client.
     pub fn new() -> Self {
         Self {
-            stream: Mutex::new(None),
-            reader: Mutex::new(None),
+            writer: Mutex::new(None),
+            connected: Arc::new(AtomicBool::new(false)),
             request_id: AtomicU64::new(1),
             connect_timeout: Duration::from_secs(10),
             read_timeout: Duration::from_secs(60),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            notifications: Arc::new(Mutex::new(HashMap::new())),
+            reader_handle: Mutex::new(None),
+            last_target: Mutex::new(None),
+            reconnect_policy: Mutex::new(ReconnectPolicy::default()),
+            handshake: Mutex::new(None),
         }
     }
 
     /// Create a new client with custom timeouts.
     pub fn with_timeouts(connect_timeout: Duration, read_timeout: Duration) -> Self {
         Self {
-            stream: Mutex::new(None),
-            reader: Mutex::new(None),
+            writer: Mutex::new(None),
+            connected: Arc::new(AtomicBool::new(false)),
             request_id: AtomicU64::new(1),
             connect_timeout,
             read_timeout,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            notifications: Arc::new(Mutex::new(HashMap::new())),
+            reader_handle: Mutex::new(None),
+            last_target: Mutex::new(None),
+            reconnect_policy: Mutex::new(ReconnectPolicy::default()),
+            handshake: Mutex::new(None),
         }
     }
 
-    /// Connect to the DevFlow service.
+    /// Tune the reconnect policy: how many times `call` retries a redial
+    /// after finding the connection dead, and the base backoff after the
+    /// first failed redial (doubled after each subsequent failure). The
+    /// first redial is made immediately.
+    pub fn set_reconnect_policy(&self, max_retries: u32, base_backoff: Duration) {
+        *self.reconnect_policy.lock().unwrap() = ReconnectPolicy { max_retries, base_backoff };
+    }
+
+    /// Connect to the DevFlow service over TCP.
     ///
     /// # Arguments
     /// * `host` - The hostname or IP address
@@ -134,31 +269,138 @@ impl TcpRpcClient {
         stream.set_read_timeout(Some(self.read_timeout))?;
         stream.set_write_timeout(Some(Duration::from_secs(10)))?;
 
-        // Clone stream for reader
-        let reader_stream = stream.try_clone()?;
-        let reader = BufReader::new(reader_stream);
+        self.connect_transport(Box::new(TcpTransport::from_stream(stream)))?;
+        *self.last_target.lock().unwrap() = Some(ConnectTarget::Tcp {
+            host: host.to_string(),
+            port,
+        });
+        if let Err(e) = self.handshake() {
+            self.disconnect();
+            return Err(e);
+        }
+        log::info!("Connected to DevFlow service at {}", addr);
+        Ok(())
+    }
 
-        // Store connections
-        *self.stream.lock().unwrap() = Some(stream);
-        *self.reader.lock().unwrap() = Some(reader);
+    /// Connect to a co-located DevFlow service over a Windows named pipe
+    /// (`\\.\pipe\<name>`), avoiding the loopback TCP port `connect` opens.
+    #[cfg(windows)]
+    pub fn connect_pipe(&self, name: &str) -> Result<(), TcpRpcError> {
+        log::info!("Connecting to DevFlow service over named pipe {}", name);
+        self.connect_transport(Box::new(NamedPipeTransport::connect(name)?))?;
+        *self.last_target.lock().unwrap() = Some(ConnectTarget::Pipe { name: name.to_string() });
+        if let Err(e) = self.handshake() {
+            self.disconnect();
+            return Err(e);
+        }
+        log::info!("Connected to DevFlow service over named pipe {}", name);
+        Ok(())
+    }
 
-        log::info!("Connected to DevFlow service at {}", addr);
+    /// Redial whichever target the last successful `connect`/`connect_pipe`
+    /// used. Used internally by `call`'s reconnect policy, but also exposed
+    /// for a caller that wants to force a reconnect without re-supplying the
+    /// address.
+    pub fn reconnect(&self) -> Result<(), TcpRpcError> {
+        match self.last_target.lock().unwrap().clone() {
+            Some(ConnectTarget::Tcp { host, port }) => self.connect(&host, port),
+            #[cfg(windows)]
+            Some(ConnectTarget::Pipe { name }) => self.connect_pipe(&name),
+            None => Err(TcpRpcError::NotConnected),
+        }
+    }
+
+    /// Negotiate the protocol version and capability set with the backend by
+    /// calling `system.version`. Performed automatically by
+    /// `connect`/`connect_pipe`; rejects the handshake outright (without
+    /// storing anything) on a protocol mismatch, so a mismatched backend
+    /// fails here instead of with a confusing mid-call error later.
+    fn handshake(&self) -> Result<(), TcpRpcError> {
+        let result = self.call_once("system.version", None)?;
+        let handshake = parse_handshake(&result)?;
+        *self.handshake.lock().unwrap() = Some(handshake);
+        Ok(())
+    }
+
+    /// Whether the negotiated handshake advertised `method`. `false` before a
+    /// handshake has completed, or if the backend didn't report a `methods`
+    /// list (older backends advertise nothing rather than everything).
+    pub fn supports(&self, method: &str) -> bool {
+        self.handshake
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|h| h.methods.contains(method))
+    }
+
+    /// The protocol version the backend reported during the handshake, if one
+    /// has completed.
+    pub fn negotiated_version(&self) -> Option<u32> {
+        self.handshake.lock().unwrap().as_ref().map(|h| h.version)
+    }
+
+    /// Connect over any `Transport`, tearing down any previous connection
+    /// first and spawning the background reader thread against the new one.
+    fn connect_transport(&self, transport: Box<dyn Transport>) -> Result<(), TcpRpcError> {
+        // Tear down any previous reader thread first so it can't keep running
+        // against a stale connection and clobber state shared with this one.
+        self.disconnect();
+
+        let (writer, mut reader) = transport.split()?;
+        *self.writer.lock().unwrap() = Some(writer);
+        self.connected.store(true, Ordering::SeqCst);
+
+        let pending = Arc::clone(&self.pending);
+        let notifications = Arc::clone(&self.notifications);
+        let connected = Arc::clone(&self.connected);
+
+        let handle = thread::spawn(move || loop {
+            let mut line = String::new();
+            match reader.read_line(&mut line) {
+                Ok(0) => {
+                    connected.store(false, Ordering::SeqCst);
+                    Self::fail_all_pending(&pending, TcpRpcError::NotConnected);
+                    break;
+                }
+                Err(e) => {
+                    connected.store(false, Ordering::SeqCst);
+                    Self::fail_all_pending(&pending, TcpRpcError::Io(e));
+                    break;
+                }
+                Ok(_) => {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    log::debug!("Received: {}", line.trim());
+                    Self::dispatch_line(&pending, &notifications, &line);
+                }
+            }
+        });
+
+        *self.reader_handle.lock().unwrap() = Some(handle);
         Ok(())
     }
 
     /// Disconnect from the service.
     pub fn disconnect(&self) {
-        *self.stream.lock().unwrap() = None;
-        *self.reader.lock().unwrap() = None;
+        *self.writer.lock().unwrap() = None;
+        self.connected.store(false, Ordering::SeqCst);
+        Self::fail_all_pending(&self.pending, TcpRpcError::NotConnected);
+        self.notifications.lock().unwrap().clear();
+        if let Some(handle) = self.reader_handle.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+        *self.handshake.lock().unwrap() = None;
         log::info!("Disconnected from DevFlow service");
     }
 
     /// Check if connected to the service.
     pub fn is_connected(&self) -> bool {
-        self.stream.lock().unwrap().is_some()
+        self.connected.load(Ordering::SeqCst)
     }
 
-    /// Call an RPC method.
+    /// Call an RPC method, transparently reconnecting and replaying the
+    /// request once if the connection turns out to be dead.
     ///
     /// # Arguments
     /// * `method` - The method name (e.g., "system.ping")
@@ -167,61 +409,192 @@ impl TcpRpcClient {
     /// # Returns
     /// The result value from the RPC call.
     pub fn call(&self, method: &str, params: Option<Value>) -> Result<Value, TcpRpcError> {
+        match self.call_once(method, params.clone()) {
+            Err(e) if !self.is_connected() => {
+                log::warn!(
+                    "RPC call {} failed ({}), attempting to reconnect",
+                    method,
+                    e
+                );
+                self.reconnect_with_backoff()?;
+                self.call_once(method, params)
+            }
+            other => other,
+        }
+    }
+
+    /// Redial the last connect target up to the configured number of
+    /// retries, doubling the backoff after each failed attempt.
+    fn reconnect_with_backoff(&self) -> Result<(), TcpRpcError> {
+        if self.last_target.lock().unwrap().is_none() {
+            // Never successfully connected, so there's nothing to redial;
+            // don't burn the retry budget sleeping for no reason.
+            return Err(TcpRpcError::NotConnected);
+        }
+
+        let policy = *self.reconnect_policy.lock().unwrap();
+        let mut backoff = policy.base_backoff;
+        let mut last_err = TcpRpcError::NotConnected;
+
+        for attempt in 1..=policy.max_retries {
+            match self.reconnect() {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    log::warn!("Reconnect attempt {}/{} failed: {}", attempt, policy.max_retries, e);
+                    last_err = e;
+                    if attempt < policy.max_retries {
+                        thread::sleep(backoff);
+                        backoff *= 2;
+                    }
+                }
+            }
+        }
+
+        Err(last_err)
+    }
+
+    /// A single RPC call attempt, with no reconnect/replay on failure. The
+    /// building block `call` retries once on top of.
+    fn call_once(&self, method: &str, params: Option<Value>) -> Result<Value, TcpRpcError> {
+        if !self.is_connected() {
+            return Err(TcpRpcError::NotConnected);
+        }
+
         let id = self.request_id.fetch_add(1, Ordering::SeqCst);
         let request = RpcRequest::new(method, params, id);
 
+        let (tx, rx) = mpsc::channel();
+        self.pending.lock().unwrap().insert(id, tx);
+
         log::debug!("RPC call: {} (id={})", method, id);
 
-        // Send request
         {
-            let mut stream_guard = self.stream.lock().unwrap();
-            let stream = stream_guard.as_mut().ok_or(TcpRpcError::NotConnected)?;
+            let mut writer_guard = self.writer.lock().unwrap();
+            let writer = match writer_guard.as_mut() {
+                Some(writer) => writer,
+                None => {
+                    self.pending.lock().unwrap().remove(&id);
+                    return Err(TcpRpcError::NotConnected);
+                }
+            };
 
             let request_json = serde_json::to_string(&request)?;
             log::debug!("Sending: {}", request_json);
 
-            writeln!(stream, "{}", request_json)?;
-            stream.flush()?;
+            if let Err(e) = writeln!(writer, "{}", request_json).and_then(|_| writer.flush()) {
+                drop(writer_guard);
+                self.pending.lock().unwrap().remove(&id);
+                return Err(TcpRpcError::Io(e));
+            }
         }
 
-        // Read response
-        {
-            let mut reader_guard = self.reader.lock().unwrap();
-            let reader = reader_guard.as_mut().ok_or(TcpRpcError::NotConnected)?;
-
-            let mut response_line = String::new();
-            reader.read_line(&mut response_line)?;
+        match rx.recv_timeout(self.read_timeout) {
+            Ok(result) => result,
+            Err(_) => {
+                self.pending.lock().unwrap().remove(&id);
+                Err(TcpRpcError::Timeout)
+            }
+        }
+    }
 
-            log::debug!("Received: {}", response_line.trim());
+    /// Test the connection with a ping.
+    pub fn ping(&self) -> Result<Value, TcpRpcError> {
+        self.call("system.ping", None)
+    }
 
-            let response: RpcResponse = serde_json::from_str(&response_line)?;
+    /// Subscribe to server-pushed notifications for `method` (a message with
+    /// no `id`, e.g. `{"method": "build.progress", "params": {...}}`). Each
+    /// matching notification's `params` is sent to the returned receiver.
+    pub fn subscribe(&self, method: &str) -> Receiver<Value> {
+        let (tx, rx) = mpsc::channel();
+        self.notifications
+            .lock()
+            .unwrap()
+            .entry(method.to_string())
+            .or_default()
+            .push(tx);
+        rx
+    }
 
-            // Check for error
-            if let Some(error) = response.error {
-                return Err(TcpRpcError::Rpc {
-                    code: error.code,
-                    message: error.message,
-                    data: error.data,
-                });
-            }
+    /// Stop delivering notifications for `method`, dropping every receiver
+    /// handed out by a prior `subscribe` call for it.
+    pub fn unsubscribe(&self, method: &str) {
+        self.notifications.lock().unwrap().remove(method);
+    }
 
-            // Verify response ID matches
-            if response.id != Some(id) {
-                return Err(TcpRpcError::InvalidResponse(format!(
-                    "Response ID {:?} doesn't match request ID {}",
-                    response.id, id
-                )));
+    /// Dispatch one line off the reader: route a response to its pending
+    /// call, or a notification (no `id`) to its subscribers.
+    fn dispatch_line(pending: &PendingMap, notifications: &NotificationMap, line: &str) {
+        let parsed: Result<RpcLine, _> = serde_json::from_str(line);
+        let parsed = match parsed {
+            Ok(p) => p,
+            Err(e) => {
+                log::warn!("Failed to parse RPC line: {} ({})", line.trim(), e);
+                return;
             }
-
-            response.result.ok_or_else(|| {
-                TcpRpcError::InvalidResponse("Response has neither result nor error".to_string())
+        };
+
+        let Some(id) = parsed.id else {
+            Self::dispatch_notification(notifications, parsed.method, parsed.params);
+            return;
+        };
+
+        let sender = pending.lock().unwrap().remove(&id);
+        let Some(sender) = sender else {
+            log::debug!("Received response for unknown/expired request id {}", id);
+            return;
+        };
+
+        let result = if let Some(error) = parsed.error {
+            Err(TcpRpcError::Rpc {
+                code: error.code,
+                message: error.message,
+                data: error.data,
             })
+        } else if let Some(result) = parsed.result {
+            Ok(result)
+        } else {
+            Err(TcpRpcError::InvalidResponse(
+                "Response has neither result nor error".to_string(),
+            ))
+        };
+
+        let _ = sender.send(result);
+    }
+
+    /// Route a notification (no `id`) to every live subscriber of its
+    /// `method`. Senders whose receiver has been dropped are pruned.
+    fn dispatch_notification(
+        notifications: &NotificationMap,
+        method: Option<String>,
+        params: Option<Value>,
+    ) {
+        let Some(method) = method else {
+            log::debug!("Ignoring message with no id and no method");
+            return;
+        };
+
+        let params = params.unwrap_or(Value::Null);
+        let mut notifications = notifications.lock().unwrap();
+        if let Some(senders) = notifications.get_mut(&method) {
+            senders.retain(|sender| sender.send(params.clone()).is_ok());
         }
     }
 
-    /// Test the connection with a ping.
-    pub fn ping(&self) -> Result<Value, TcpRpcError> {
-        self.call("system.ping", None)
+    /// Deliver `error` to every pending call and forget it, so a dead
+    /// connection doesn't leave callers blocked forever. Each waiter gets its
+    /// own copy of `error` reconstructed from its `Display` text, since
+    /// `TcpRpcError` (carrying a non-`Clone` `io::Error`) can't be cloned
+    /// directly.
+    fn fail_all_pending(pending: &PendingMap, error: TcpRpcError) {
+        let mut map = pending.lock().unwrap();
+        for (_, sender) in map.drain() {
+            let err = match &error {
+                TcpRpcError::NotConnected => TcpRpcError::NotConnected,
+                other => TcpRpcError::InvalidResponse(other.to_string()),
+            };
+            let _ = sender.send(Err(err));
+        }
     }
 }
 
@@ -237,9 +610,102 @@ impl Drop for TcpRpcClient {
     }
 }
 
+/// A pool of `TcpRpcClient` connections to the same `host:port`, so several
+/// slow RPC calls (e.g. `run_migrations`, `deploy`) can be in flight at once
+/// instead of serializing behind a single connection. Connections are
+/// checked out round-robin via `get()` and returned to the pool when the
+/// guard drops; a connection found disconnected on return is reconnected
+/// before being handed out again.
+pub struct TcpConnectionPool {
+    host: String,
+    port: u16,
+    size: usize,
+    idle: Mutex<VecDeque<Arc<TcpRpcClient>>>,
+}
+
+impl TcpConnectionPool {
+    /// Create and connect a pool of `size` clients to `host:port`. `size` is
+    /// forced to at least 1.
+    pub fn connect(host: &str, port: u16, size: usize) -> Result<Self, TcpRpcError> {
+        let size = size.max(1);
+        let mut idle = VecDeque::with_capacity(size);
+        for _ in 0..size {
+            let client = TcpRpcClient::new();
+            client.connect(host, port)?;
+            idle.push_back(Arc::new(client));
+        }
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            size,
+            idle: Mutex::new(idle),
+        })
+    }
+
+    /// Number of connections the pool opened in `connect`. This is not a cap:
+    /// `get` opens a further connection when none is idle, and that one joins
+    /// the idle queue when its guard drops.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Check out a connection, reconnecting it first if a prior checkout
+    /// left it disconnected (e.g. after an IO error).
+    pub fn get(&self) -> Result<PooledConnection<'_>, TcpRpcError> {
+        let client = self
+            .idle
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_else(|| Arc::new(TcpRpcClient::new()));
+
+        if !client.is_connected() {
+            client.connect(&self.host, self.port)?;
+        }
+
+        Ok(PooledConnection {
+            pool: self,
+            client: Some(client),
+        })
+    }
+
+    /// Disconnect and drop every idle connection, e.g. when the pool is
+    /// being torn down along with the backend.
+    pub fn disconnect_all(&self) {
+        for client in self.idle.lock().unwrap().drain(..) {
+            client.disconnect();
+        }
+    }
+}
+
+/// A checked-out pool connection. Derefs to `TcpRpcClient`; returns itself to
+/// the pool's idle queue on drop.
+pub struct PooledConnection<'a> {
+    pool: &'a TcpConnectionPool,
+    client: Option<Arc<TcpRpcClient>>,
+}
+
+impl std::ops::Deref for PooledConnection<'_> {
+    type Target = TcpRpcClient;
+
+    fn deref(&self) -> &Self::Target {
+        self.client.as_ref().expect("client taken before drop")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            self.pool.idle.lock().unwrap().push_back(client);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_rpc_request_serialization() {
@@ -258,4 +724,150 @@ mod tests {
         let result = client.call("system.ping", None);
         assert!(matches!(result, Err(TcpRpcError::NotConnected)));
     }
+
+    #[test]
+    fn test_subscribe_and_dispatch_notification() {
+        let client = TcpRpcClient::new();
+        let rx = client.subscribe("build.progress");
+        TcpRpcClient::dispatch_notification(
+            &client.notifications,
+            Some("build.progress".to_string()),
+            Some(json!({"pct": 50})),
+        );
+        let value = rx.recv_timeout(Duration::from_millis(100)).unwrap();
+        assert_eq!(value["pct"], 50);
+    }
+
+    #[test]
+    fn test_unsubscribe_drops_future_notifications() {
+        let client = TcpRpcClient::new();
+        let rx = client.subscribe("build.progress");
+        client.unsubscribe("build.progress");
+        TcpRpcClient::dispatch_notification(
+            &client.notifications,
+            Some("build.progress".to_string()),
+            Some(json!({})),
+        );
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn test_dispatch_notification_ignores_message_without_method() {
+        let client = TcpRpcClient::new();
+        let rx = client.subscribe("build.progress");
+        TcpRpcClient::dispatch_notification(&client.notifications, None, Some(json!({})));
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn test_dispatch_line_routes_by_id() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let notifications: NotificationMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel();
+        pending.lock().unwrap().insert(7, tx);
+
+        TcpRpcClient::dispatch_line(
+            &pending,
+            &notifications,
+            r#"{"jsonrpc":"2.0","result":{"ok":true},"id":7}"#,
+        );
+
+        let result = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(result.unwrap(), json!({"ok": true}));
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_line_drops_response_for_unknown_id() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let notifications: NotificationMap = Mutex::new(HashMap::new());
+        // Should not panic even though nothing is pending for id 1.
+        TcpRpcClient::dispatch_line(
+            &pending,
+            &notifications,
+            r#"{"jsonrpc":"2.0","result":{},"id":1}"#,
+        );
+    }
+
+    #[test]
+    fn test_dispatch_line_routes_notification_to_subscriber() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let notifications: NotificationMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel();
+        notifications
+            .lock()
+            .unwrap()
+            .entry("build.progress".to_string())
+            .or_default()
+            .push(tx);
+
+        TcpRpcClient::dispatch_line(
+            &pending,
+            &notifications,
+            r#"{"jsonrpc":"2.0","method":"build.progress","params":{"pct":75}}"#,
+        );
+
+        let value = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(value["pct"], 75);
+    }
+
+    #[test]
+    fn test_fail_all_pending_delivers_not_connected() {
+        let pending: PendingMap = Mutex::new(HashMap::new());
+        let (tx, rx) = mpsc::channel();
+        pending.lock().unwrap().insert(1, tx);
+
+        TcpRpcClient::fail_all_pending(&pending, TcpRpcError::NotConnected);
+
+        match rx.recv_timeout(Duration::from_secs(1)).unwrap() {
+            Err(TcpRpcError::NotConnected) => (),
+            other => panic!("Expected NotConnected, got {:?}", other),
+        }
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_handshake_accepts_matching_version() {
+        let result = json!({"protocol": SUPPORTED_PROTOCOL, "methods": ["system.ping"]});
+        let handshake = parse_handshake(&result).unwrap();
+        assert_eq!(handshake.version, SUPPORTED_PROTOCOL);
+        assert!(handshake.methods.contains("system.ping"));
+    }
+
+    #[test]
+    fn test_parse_handshake_rejects_mismatch() {
+        let result = json!({"protocol": SUPPORTED_PROTOCOL + 1});
+        match parse_handshake(&result) {
+            Err(TcpRpcError::ProtocolMismatch { client, server }) => {
+                assert_eq!(client, SUPPORTED_PROTOCOL);
+                assert_eq!(server, SUPPORTED_PROTOCOL + 1);
+            }
+            other => panic!("Expected ProtocolMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_handshake_allows_missing_field() {
+        let result = json!({"version": "1.2.3"});
+        let handshake = parse_handshake(&result).unwrap();
+        assert_eq!(handshake.version, SUPPORTED_PROTOCOL);
+        assert!(handshake.methods.is_empty());
+    }
+
+    #[test]
+    fn test_supports_false_before_handshake() {
+        let client = TcpRpcClient::new();
+        assert!(!client.supports("db.rollback"));
+        assert_eq!(client.negotiated_version(), None);
+    }
+
+    #[test]
+    fn test_reconnect_with_backoff_short_circuits_when_never_connected() {
+        let client = TcpRpcClient::new();
+        client.set_reconnect_policy(3, Duration::from_secs(30));
+        let start = std::time::Instant::now();
+        let result = client.reconnect_with_backoff();
+        assert!(matches!(result, Err(TcpRpcError::NotConnected)));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
 }
@@ -0,0 +1,109 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::process::{ChildStdin, ChildStdout};
+
+/// A framed duplex connection to the bridge process: one line in, one line
+/// out. `RpcClient` is written against this trait instead of a concrete
+/// stdin/stdout pair so it can run over a locally spawned subprocess, a Unix
+/// domain socket, or a TCP socket without caring which.
+///
+/// Implementations must split cleanly into an owned writer half and an
+/// owned, line-buffered reader half so the background reader thread and a
+/// `call`'s writer can each hold their half independently.
+pub trait Transport: Send {
+    fn split(self: Box<Self>) -> io::Result<(Box<dyn Write + Send>, Box<dyn BufRead + Send>)>;
+}
+
+/// The original transport: a locally spawned subprocess's stdin/stdout pipes.
+pub struct ChildPipeTransport {
+    pub stdin: ChildStdin,
+    pub stdout: ChildStdout,
+}
+
+impl ChildPipeTransport {
+    pub fn new(stdin: ChildStdin, stdout: ChildStdout) -> Self {
+        Self { stdin, stdout }
+    }
+}
+
+impl Transport for ChildPipeTransport {
+    fn split(self: Box<Self>) -> io::Result<(Box<dyn Write + Send>, Box<dyn BufRead + Send>)> {
+        Ok((Box::new(self.stdin), Box::new(BufReader::new(self.stdout))))
+    }
+}
+
+/// Connects over a TCP socket, e.g. to a bridge running on a remote
+/// deployment node (see `get_ssh_command`).
+pub struct TcpTransport {
+    stream: TcpStream,
+}
+
+impl TcpTransport {
+    pub fn connect(host: &str, port: u16) -> io::Result<Self> {
+        let stream = TcpStream::connect((host, port))?;
+        Ok(Self { stream })
+    }
+
+    /// Wrap an already-connected, already-configured `TcpStream` (e.g. one
+    /// opened with `TcpStream::connect_timeout` and custom socket options
+    /// already set), instead of dialing one itself.
+    pub fn from_stream(stream: TcpStream) -> Self {
+        Self { stream }
+    }
+}
+
+impl Transport for TcpTransport {
+    fn split(self: Box<Self>) -> io::Result<(Box<dyn Write + Send>, Box<dyn BufRead + Send>)> {
+        let reader = self.stream.try_clone()?;
+        Ok((Box::new(self.stream), Box::new(BufReader::new(reader))))
+    }
+}
+
+/// Connects over a Unix domain socket, e.g. a `bridge.sock` left by a bridge
+/// process running in a container or on the same host.
+#[cfg(unix)]
+pub struct UnixSocketTransport {
+    stream: std::os::unix::net::UnixStream,
+}
+
+#[cfg(unix)]
+impl UnixSocketTransport {
+    pub fn connect(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+        let stream = std::os::unix::net::UnixStream::connect(path)?;
+        Ok(Self { stream })
+    }
+}
+
+#[cfg(unix)]
+impl Transport for UnixSocketTransport {
+    fn split(self: Box<Self>) -> io::Result<(Box<dyn Write + Send>, Box<dyn BufRead + Send>)> {
+        let reader = self.stream.try_clone()?;
+        Ok((Box::new(self.stream), Box::new(BufReader::new(reader))))
+    }
+}
+
+/// Connects over a Windows named pipe, the Windows analogue of
+/// `UnixSocketTransport` for a co-located bridge: it avoids exposing a TCP
+/// port on the host. Named pipes show up in the filesystem namespace under
+/// `\\.\pipe\<name>`, so a byte-mode pipe can be opened like a regular file.
+#[cfg(windows)]
+pub struct NamedPipeTransport {
+    file: std::fs::File,
+}
+
+#[cfg(windows)]
+impl NamedPipeTransport {
+    pub fn connect(pipe_name: &str) -> io::Result<Self> {
+        let path = format!(r"\\.\pipe\{}", pipe_name);
+        let file = std::fs::OpenOptions::new().read(true).write(true).open(path)?;
+        Ok(Self { file })
+    }
+}
+
+#[cfg(windows)]
+impl Transport for NamedPipeTransport {
+    fn split(self: Box<Self>) -> io::Result<(Box<dyn Write + Send>, Box<dyn BufRead + Send>)> {
+        let reader = self.file.try_clone()?;
+        Ok((Box::new(self.file), Box::new(BufReader::new(reader))))
+    }
+}
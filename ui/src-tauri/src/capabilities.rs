@@ -0,0 +1,110 @@
+//! Capability subsystem: a JSON allowlist of bridge RPC methods this window
+//! may invoke, selected per build target (desktop vs mobile) and filtered
+//! per rule by the active backend type.
+//!
+//! `bridge_call`/`bridge_call_checked` consult this before every dispatch, so
+//! any command built on top of them - including the sensitive ones (`deploy`,
+//! `rollback_deploy`, `sync_secrets`, `export_secrets`, `exec_in_container`) -
+//! is covered without each needing its own check.
+
+use crate::backend::{BackendType, GlobalBackendConfig};
+use serde::Deserialize;
+
+/// One allowlist entry. `method` is an exact bridge RPC method
+/// (`"deploy.deploy"`) or a module wildcard (`"infra.*"`). `requires_backend`
+/// restricts the rule to specific backend types - see `backend_type_key` for
+/// the string each `BackendType` maps to - and is omitted for rules that
+/// apply regardless of backend.
+#[derive(Debug, Clone, Deserialize)]
+struct CapabilityRule {
+    method: String,
+    #[serde(default)]
+    requires_backend: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+struct CapabilityFile {
+    #[serde(default)]
+    allow: Vec<CapabilityRule>,
+}
+
+/// The capability file baked into this build. Desktop builds get the full
+/// command surface; mobile builds (a read-only companion view) get a
+/// deliberately small subset of status, history, log and listing methods,
+/// with nothing that deploys, changes secrets or execs into a container.
+#[cfg(not(mobile))]
+const CAPABILITY_FILE: &str = include_str!("../capabilities/desktop.json");
+#[cfg(mobile)]
+const CAPABILITY_FILE: &str = include_str!("../capabilities/mobile.json");
+
+/// The capability set in effect for the current window: this build target's
+/// allowlist, resolved against whichever backend type is currently active.
+pub struct CapabilitySet {
+    rules: Vec<CapabilityRule>,
+    active_backend: Option<String>,
+}
+
+impl CapabilitySet {
+    /// Load the build target's capability file and resolve the active
+    /// backend type from the global backend config.
+    pub fn load() -> Self {
+        let file: CapabilityFile = serde_json::from_str(CAPABILITY_FILE).unwrap_or_default();
+        let active_backend = GlobalBackendConfig::load()
+            .active_config()
+            .map(|config| backend_type_key(&config.backend_type).to_string());
+
+        Self {
+            rules: file.allow,
+            active_backend,
+        }
+    }
+
+    /// Whether `method` (a bridge RPC method, e.g. `"deploy.rollback"`) is
+    /// permitted for the active backend.
+    pub fn allows(&self, method: &str) -> bool {
+        self.rules.iter().any(|rule| {
+            let method_matches = rule.method == method
+                || rule
+                    .method
+                    .strip_suffix(".*")
+                    .is_some_and(|prefix| method.starts_with(prefix));
+
+            method_matches
+                && match &rule.requires_backend {
+                    None => true,
+                    Some(types) => self
+                        .active_backend
+                        .as_deref()
+                        .is_some_and(|active| types.iter().any(|t| t == active)),
+                }
+        })
+    }
+
+    /// The exact methods currently permitted, for `get_active_capabilities`.
+    /// Wildcard entries are reported as written (e.g. `"infra.*"`) rather
+    /// than expanded, since the set of methods they cover is defined by the
+    /// bridge, not by this allowlist.
+    pub fn allowed_methods(&self) -> Vec<String> {
+        self.rules
+            .iter()
+            .filter(|rule| match &rule.requires_backend {
+                None => true,
+                Some(types) => self
+                    .active_backend
+                    .as_deref()
+                    .is_some_and(|active| types.iter().any(|t| t == active)),
+            })
+            .map(|rule| rule.method.clone())
+            .collect()
+    }
+}
+
+fn backend_type_key(backend_type: &BackendType) -> &'static str {
+    match backend_type {
+        BackendType::LocalPython => "local_python",
+        BackendType::Docker => "docker",
+        BackendType::DockerCompose => "docker_compose",
+        BackendType::Wsl2 => "wsl2",
+        BackendType::Remote => "remote",
+    }
+}
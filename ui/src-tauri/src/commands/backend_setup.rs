@@ -5,17 +5,28 @@
 
 use super::CommandResponse;
 use crate::backend::{
-    check_devflow_installed, check_docker_container, detect_all_prerequisites, detect_docker,
-    detect_python, detect_wsl, detect_wsl_distros_detailed, install_devflow_local,
-    install_devflow_wsl_with_progress, pull_docker_image_with_progress, remove_docker_container,
-    start_docker_container, start_wsl_distro, start_wsl_service, stop_docker_container,
-    stop_wsl_service, test_devflow_connection, validate_wsl_installation, BackendConfig,
-    BackendType, GlobalBackendConfig, PrerequisiteStatus, WslDistroStatus, WslInstallValidation,
+    check_devflow_installed, check_docker_container, compose_down, compose_service_running,
+    compose_up_with_progress, default_compose_path, detect_all_prerequisites, detect_docker,
+    detect_python, detect_wsl, detect_wsl_distros_detailed, docker_backend_status,
+    install_devflow_local, install_devflow_wsl_with_progress, list_docker_prune_candidates,
+    parse_volume_mount, preflight_docker_backend, prune_docker_images,
+    pull_docker_image_with_layer_progress, pull_docker_image_with_progress,
+    remove_docker_container, start_docker_container, start_docker_container_with_options,
+    start_wsl_distro, start_wsl_service, stop_docker_container, stop_wsl_service,
+    test_devflow_connection, validate_wsl_installation, write_compose_file, BackendConfig,
+    BackendType, ComposeOptions, DistroInfo, DockerBackendStatus, DockerPruneCandidates,
+    DockerRunOptions, GlobalBackendConfig, InterpreterInfo, PreflightReport, PreflightSeverity,
+    PrerequisiteStatus, PythonCompatibility, WslDistroStatus, WslInstallValidation,
+    COMPOSE_SERVICE_NAME, DEFAULT_DOCKER_IMAGE,
 };
 use crate::bridge::{BridgeManager, ConnectionMode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter, State};
 
 /// Response for prerequisite detection.
@@ -24,6 +35,8 @@ pub struct PrerequisiteResponse {
     pub python_available: bool,
     pub python_version: Option<String>,
     pub python_path: Option<String>,
+    pub interpreter: Option<InterpreterInfo>,
+    pub python_compatibility: Option<PythonCompatibility>,
     pub devflow_installed: bool,
     pub devflow_version: Option<String>,
     pub docker_available: bool,
@@ -31,6 +44,8 @@ pub struct PrerequisiteResponse {
     pub docker_version: Option<String>,
     pub wsl_available: bool,
     pub wsl_distros: Vec<String>,
+    pub running_in_container: bool,
+    pub distro: Option<DistroInfo>,
 }
 
 impl From<PrerequisiteStatus> for PrerequisiteResponse {
@@ -39,6 +54,8 @@ impl From<PrerequisiteStatus> for PrerequisiteResponse {
             python_available: status.python_available,
             python_version: status.python_version,
             python_path: status.python_path.map(|p| p.to_string_lossy().to_string()),
+            interpreter: status.interpreter,
+            python_compatibility: status.python_compatibility,
             devflow_installed: status.devflow_installed,
             devflow_version: status.devflow_version,
             docker_available: status.docker_available,
@@ -46,6 +63,8 @@ impl From<PrerequisiteStatus> for PrerequisiteResponse {
             docker_version: status.docker_version,
             wsl_available: status.wsl_available,
             wsl_distros: status.wsl_distros,
+            running_in_container: status.running_in_container,
+            distro: status.distro,
         }
     }
 }
@@ -90,6 +109,75 @@ pub fn save_backend_config(config: BackendConfig) -> CommandResponse<()> {
     }
 }
 
+/// A named backend profile, as listed for the profile switcher UI.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BackendProfileSummary {
+    pub name: String,
+    pub config: BackendConfig,
+    pub active: bool,
+}
+
+/// List the saved backend profiles and which one is active.
+#[tauri::command]
+pub fn list_backend_profiles() -> CommandResponse<Vec<BackendProfileSummary>> {
+    let global_config = GlobalBackendConfig::load();
+    let active = global_config.active_profile_name();
+    let mut profiles: Vec<BackendProfileSummary> = global_config
+        .list_profiles()
+        .into_iter()
+        .map(|(name, config)| {
+            let active = name == active;
+            BackendProfileSummary {
+                name,
+                config,
+                active,
+            }
+        })
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    CommandResponse::ok(profiles)
+}
+
+/// Add (or replace) a named backend profile, without activating it.
+#[tauri::command]
+pub fn add_backend_profile(name: String, config: BackendConfig) -> CommandResponse<()> {
+    log::info!("Adding backend profile \"{}\"", name);
+    let mut global_config = GlobalBackendConfig::load();
+    global_config.add_profile(name, config);
+    match global_config.save() {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(e),
+    }
+}
+
+/// Remove a named backend profile. Fails if it is the active profile.
+#[tauri::command]
+pub fn remove_backend_profile(name: String) -> CommandResponse<()> {
+    log::info!("Removing backend profile \"{}\"", name);
+    let mut global_config = GlobalBackendConfig::load();
+    if let Err(e) = global_config.remove_profile(&name) {
+        return CommandResponse::err(e);
+    }
+    match global_config.save() {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(e),
+    }
+}
+
+/// Activate a named backend profile as the default for new bridge starts.
+#[tauri::command]
+pub fn activate_backend_profile(name: String) -> CommandResponse<()> {
+    log::info!("Activating backend profile \"{}\"", name);
+    let mut global_config = GlobalBackendConfig::load();
+    if let Err(e) = global_config.activate_profile(&name) {
+        return CommandResponse::err(e);
+    }
+    match global_config.save() {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(e),
+    }
+}
+
 /// Install log entry for streaming to frontend.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InstallLogEntry {
@@ -111,6 +199,112 @@ fn emit_install_log(app: &AppHandle, level: &str, message: &str, output: Option<
     let _ = app.emit("install-log", entry);
 }
 
+/// Build `DockerRunOptions` from the `config` map passed to `install_backend`,
+/// validating that every bind mount's host directory actually exists first -
+/// `docker run -v` on a missing host path silently creates an empty one
+/// instead of failing, which would otherwise hide a typo'd project path.
+fn docker_run_options_from_value(config: Option<&Value>) -> Result<DockerRunOptions, String> {
+    let mounts = config
+        .and_then(|c| c.get("mounts"))
+        .and_then(Value::as_array)
+        .map(|arr| arr.iter().filter_map(Value::as_str).map(str::to_string).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut extra_mounts = Vec::with_capacity(mounts.len());
+    let mut missing = Vec::new();
+    for spec in &mounts {
+        let mount = parse_volume_mount(spec)?;
+        if !mount.host_path.exists() {
+            missing.push(mount.host_path.display().to_string());
+        }
+        extra_mounts.push(mount);
+    }
+    if !missing.is_empty() {
+        return Err(format!("Host directories do not exist: {}", missing.join(", ")));
+    }
+
+    let string_field = |key: &str| {
+        config
+            .and_then(|c| c.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    Ok(DockerRunOptions {
+        extra_mounts,
+        memory: string_field("memory"),
+        cpus: string_field("cpus"),
+        shm_size: string_field("shm_size"),
+        network_mode: string_field("network_mode"),
+        ..DockerRunOptions::default()
+    })
+}
+
+/// Resolve the compose file path and `devflow` service options from the
+/// `config` map passed to `install_backend` for the DockerCompose backend,
+/// reading its `compose_path`/`image`/`port`/`restart_policy` keys.
+fn docker_compose_settings_from_value(config: Option<&Value>) -> (std::path::PathBuf, ComposeOptions) {
+    let compose_path = config
+        .and_then(|c| c.get("compose_path")?.as_str())
+        .map(std::path::PathBuf::from)
+        .or_else(default_compose_path)
+        .unwrap_or_else(|| std::path::PathBuf::from("docker-compose.yml"));
+
+    let mut options = ComposeOptions::default();
+    if let Some(port) = config.and_then(|c| c.get("port")?.as_u64()) {
+        options.port = port as u16;
+    }
+    if let Some(image) = config.and_then(|c| c.get("image")?.as_str()) {
+        options.image_ref = image.to_string();
+    }
+    if let Some(policy) = config.and_then(|c| c.get("restart_policy")?.as_str()) {
+        options.restart_policy = policy.to_string();
+    }
+
+    (compose_path, options)
+}
+
+/// Resolve a DockerCompose `BackendConfig`'s compose file path, falling back
+/// to `~/.devflow/docker-compose.yml` when unset.
+fn resolved_compose_path(config: &BackendConfig) -> std::path::PathBuf {
+    config
+        .compose_path
+        .clone()
+        .or_else(default_compose_path)
+        .unwrap_or_else(|| std::path::PathBuf::from("docker-compose.yml"))
+}
+
+/// Audit the live Docker state for conflicts before installing `config`'s
+/// backend: a same-named or same-port container, pre-existing devflow
+/// volumes/networks, local image presence, and host port availability.
+///
+/// Only Docker and DockerCompose backends have anything to audit - other
+/// backend types return an empty report.
+#[tauri::command]
+pub fn backend_preflight(config: BackendConfig) -> CommandResponse<PreflightReport> {
+    log::info!("Running backend preflight for {:?}", config.backend_type);
+
+    let report = match config.backend_type {
+        BackendType::Docker => {
+            let container_name = config
+                .container_name
+                .clone()
+                .unwrap_or_else(|| "devflow-backend".to_string());
+            preflight_docker_backend(&container_name, config.tcp_port(), DEFAULT_DOCKER_IMAGE)
+        }
+        BackendType::DockerCompose => {
+            let image_ref = config
+                .compose_image
+                .clone()
+                .unwrap_or_else(|| DEFAULT_DOCKER_IMAGE.to_string());
+            preflight_docker_backend(COMPOSE_SERVICE_NAME, config.tcp_port(), &image_ref)
+        }
+        _ => PreflightReport::default(),
+    };
+
+    CommandResponse::ok(report)
+}
+
 /// Install the backend based on type.
 #[tauri::command]
 pub fn install_backend(backend_type: BackendType, config: Option<Value>) -> CommandResponse<String> {
@@ -130,8 +324,13 @@ pub fn install_backend(backend_type: BackendType, config: Option<Value>) -> Comm
             }
         }
         BackendType::Docker => {
+            let run_options = match docker_run_options_from_value(config.as_ref()) {
+                Ok(options) => options,
+                Err(e) => return CommandResponse::err(e),
+            };
+
             // Pull the image
-            let pull_result = pull_docker_image_with_progress(|msg| {
+            let pull_result = pull_docker_image_with_progress(DEFAULT_DOCKER_IMAGE, |msg| {
                 log::info!("{}", msg);
             });
             if !pull_result.success {
@@ -142,19 +341,38 @@ pub fn install_backend(backend_type: BackendType, config: Option<Value>) -> Comm
             let container_name = config
                 .as_ref()
                 .and_then(|c| c.get("container_name")?.as_str())
-                .unwrap_or("devflow-backend");
+                .unwrap_or("devflow-backend")
+                .to_string();
             let port = config
                 .as_ref()
                 .and_then(|c| c.get("port")?.as_u64())
                 .unwrap_or(9876) as u16;
 
-            let start_result = start_docker_container(container_name, port);
+            let start_result = start_docker_container_with_options(&container_name, port, &run_options);
             if start_result.success {
                 CommandResponse::ok(start_result.message)
             } else {
                 CommandResponse::err(start_result.message)
             }
         }
+        BackendType::DockerCompose => {
+            let (compose_path, compose_options) = docker_compose_settings_from_value(config.as_ref());
+
+            if !compose_path.exists() {
+                if let Err(e) = write_compose_file(&compose_path, &compose_options) {
+                    return CommandResponse::err(e);
+                }
+            }
+
+            let up_result = compose_up_with_progress(&compose_path, |msg| {
+                log::info!("{}", msg);
+            });
+            if up_result.success {
+                CommandResponse::ok(up_result.message)
+            } else {
+                CommandResponse::err(up_result.message)
+            }
+        }
         BackendType::Wsl2 => {
             let distro = config
                 .as_ref()
@@ -236,11 +454,48 @@ pub async fn install_backend_with_logs(
         BackendType::Docker => {
             emit_install_log(&app, "info", "Starting Docker installation...", None);
 
+            let run_options = match docker_run_options_from_value(config.as_ref()) {
+                Ok(options) => options,
+                Err(e) => {
+                    emit_install_log(&app, "error", &e, None);
+                    return CommandResponse::err(e);
+                }
+            };
+
+            let preflight_container_name = config
+                .as_ref()
+                .and_then(|c| c.get("container_name")?.as_str())
+                .unwrap_or("devflow-backend")
+                .to_string();
+            let preflight_port = config
+                .as_ref()
+                .and_then(|c| c.get("port")?.as_u64())
+                .unwrap_or(9876) as u16;
+
+            emit_install_log(&app, "info", "Checking for port/container conflicts...", None);
+            let preflight = preflight_docker_backend(&preflight_container_name, preflight_port, DEFAULT_DOCKER_IMAGE);
+            if preflight.has_blockers() {
+                let blockers = preflight
+                    .findings
+                    .iter()
+                    .filter(|f| f.severity == PreflightSeverity::Blocker)
+                    .map(|f| format!("{} ({})", f.message, f.remediation))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                emit_install_log(&app, "error", &format!("Preflight check failed: {}", blockers), None);
+                return CommandResponse::err(format!("Preflight check failed: {}", blockers));
+            }
+            for finding in &preflight.findings {
+                if finding.severity != PreflightSeverity::Blocker {
+                    emit_install_log(&app, "warning", &finding.message, None);
+                }
+            }
+
             // Pull the image with progress
             emit_install_log(&app, "info", "Pulling Docker image ghcr.io/ao-cyber-systems/devflow:latest...", None);
 
             let app_clone = app.clone();
-            let pull_result = pull_docker_image_with_progress(move |msg| {
+            let pull_result = pull_docker_image_with_progress(DEFAULT_DOCKER_IMAGE, move |msg| {
                 emit_install_log(&app_clone, "info", msg, None);
             });
 
@@ -254,14 +509,15 @@ pub async fn install_backend_with_logs(
             let container_name = config
                 .as_ref()
                 .and_then(|c| c.get("container_name")?.as_str())
-                .unwrap_or("devflow-backend");
+                .unwrap_or("devflow-backend")
+                .to_string();
             let port = config
                 .as_ref()
                 .and_then(|c| c.get("port")?.as_u64())
                 .unwrap_or(9876) as u16;
 
             emit_install_log(&app, "info", &format!("Starting container '{}' on port {}...", container_name, port), None);
-            let start_result = start_docker_container(container_name, port);
+            let start_result = start_docker_container_with_options(&container_name, port, &run_options);
 
             if start_result.success {
                 emit_install_log(&app, "success", &start_result.message, None);
@@ -271,6 +527,54 @@ pub async fn install_backend_with_logs(
                 CommandResponse::err(start_result.message)
             }
         }
+        BackendType::DockerCompose => {
+            emit_install_log(&app, "info", "Starting Docker Compose installation...", None);
+
+            let (compose_path, compose_options) = docker_compose_settings_from_value(config.as_ref());
+
+            emit_install_log(&app, "info", "Checking for port/container conflicts...", None);
+            let preflight = preflight_docker_backend(COMPOSE_SERVICE_NAME, compose_options.port, &compose_options.image_ref);
+            if preflight.has_blockers() {
+                let blockers = preflight
+                    .findings
+                    .iter()
+                    .filter(|f| f.severity == PreflightSeverity::Blocker)
+                    .map(|f| format!("{} ({})", f.message, f.remediation))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                emit_install_log(&app, "error", &format!("Preflight check failed: {}", blockers), None);
+                return CommandResponse::err(format!("Preflight check failed: {}", blockers));
+            }
+            for finding in &preflight.findings {
+                if finding.severity != PreflightSeverity::Blocker {
+                    emit_install_log(&app, "warning", &finding.message, None);
+                }
+            }
+
+            if !compose_path.exists() {
+                emit_install_log(&app, "info", &format!("Generating compose file at {}...", compose_path.display()), None);
+                if let Err(e) = write_compose_file(&compose_path, &compose_options) {
+                    emit_install_log(&app, "error", &e, None);
+                    return CommandResponse::err(e);
+                }
+            } else {
+                emit_install_log(&app, "info", &format!("Using existing compose file at {}...", compose_path.display()), None);
+            }
+
+            emit_install_log(&app, "info", "Running docker compose up -d...", None);
+            let app_clone = app.clone();
+            let up_result = compose_up_with_progress(&compose_path, move |msg| {
+                emit_install_log(&app_clone, "info", msg, None);
+            });
+
+            if up_result.success {
+                emit_install_log(&app, "success", &up_result.message, None);
+                CommandResponse::ok(up_result.message)
+            } else {
+                emit_install_log(&app, "error", &up_result.message, None);
+                CommandResponse::err(up_result.message)
+            }
+        }
         BackendType::Wsl2 => {
             let distro = config
                 .as_ref()
@@ -353,6 +657,15 @@ pub fn start_backend_service(config: BackendConfig) -> CommandResponse<()> {
                 CommandResponse::err(result.message)
             }
         }
+        BackendType::DockerCompose => {
+            let compose_path = resolved_compose_path(&config);
+            let result = compose_up_with_progress(&compose_path, |msg| log::info!("{}", msg));
+            if result.success {
+                CommandResponse::ok(())
+            } else {
+                CommandResponse::err(result.message)
+            }
+        }
         BackendType::Wsl2 => {
             let distro = config.wsl_distro.as_deref().unwrap_or("Ubuntu");
             let port = config.tcp_port();
@@ -389,6 +702,15 @@ pub fn stop_backend_service(config: BackendConfig) -> CommandResponse<()> {
                 CommandResponse::err(result.message)
             }
         }
+        BackendType::DockerCompose => {
+            let compose_path = resolved_compose_path(&config);
+            let result = compose_down(&compose_path);
+            if result.success {
+                CommandResponse::ok(())
+            } else {
+                CommandResponse::err(result.message)
+            }
+        }
         BackendType::Wsl2 => {
             let distro = config.wsl_distro.as_deref().unwrap_or("Ubuntu");
             let port = config.tcp_port();
@@ -406,6 +728,33 @@ pub fn stop_backend_service(config: BackendConfig) -> CommandResponse<()> {
     }
 }
 
+/// List stopped devflow containers and dangling/old-tag devflow images that
+/// `prune_docker_backend` would remove, along with the estimated bytes that
+/// would be reclaimed, so the UI can show candidates before the user confirms.
+#[tauri::command]
+pub fn list_docker_prune_backend_candidates(all: bool) -> CommandResponse<DockerPruneCandidates> {
+    CommandResponse::ok(list_docker_prune_candidates(all))
+}
+
+/// Remove stopped devflow containers and dangling/old-tag devflow images
+/// (`all` also drops non-`:latest` tags), emitting `install-log` progress as
+/// each candidate is removed.
+#[tauri::command]
+pub fn prune_docker_backend(app: AppHandle, all: bool) -> CommandResponse<String> {
+    emit_install_log(&app, "info", "Checking for prunable devflow containers and images...", None);
+
+    let app_clone = app.clone();
+    let result = prune_docker_images(all, &move |msg| emit_install_log(&app_clone, "info", msg, None));
+
+    if result.success {
+        emit_install_log(&app, "success", &result.message, None);
+        CommandResponse::ok(result.message)
+    } else {
+        emit_install_log(&app, "error", &result.message, None);
+        CommandResponse::err(result.message)
+    }
+}
+
 /// Test connection to the backend.
 #[tauri::command]
 pub fn test_backend_connection(config: BackendConfig) -> CommandResponse<bool> {
@@ -429,6 +778,15 @@ pub fn test_backend_connection(config: BackendConfig) -> CommandResponse<bool> {
             let connected = test_devflow_connection(&config.tcp_host(), config.tcp_port());
             CommandResponse::ok(connected)
         }
+        BackendType::DockerCompose => {
+            // Check if the devflow service is up and responsive
+            let compose_path = resolved_compose_path(&config);
+            if !compose_service_running(&compose_path, COMPOSE_SERVICE_NAME) {
+                return CommandResponse::ok(false);
+            }
+            let connected = test_devflow_connection(&config.tcp_host(), config.tcp_port());
+            CommandResponse::ok(connected)
+        }
         BackendType::Wsl2 | BackendType::Remote => {
             // Test TCP connection
             let connected = test_devflow_connection(&config.tcp_host(), config.tcp_port());
@@ -457,9 +815,13 @@ pub async fn start_bridge_with_config(
                 Err(e) => Ok(CommandResponse::err(format!("{}", e))),
             }
         }
-        BackendType::Docker | BackendType::Wsl2 | BackendType::Remote => {
+        BackendType::Docker | BackendType::DockerCompose | BackendType::Wsl2 | BackendType::Remote => {
             bridge.set_mode(ConnectionMode::Tcp);
-            bridge.set_tcp_config(config.tcp_host(), config.tcp_port());
+            bridge.set_tcp_config_pooled(
+                config.tcp_host(),
+                config.tcp_port(),
+                config.effective_pool_size(),
+            );
             // Start in TCP mode
             match bridge.start("", None) {
                 Ok(()) => Ok(CommandResponse::ok(())),
@@ -479,6 +841,17 @@ pub fn get_recommended_backend() -> CommandResponse<BackendType> {
         return CommandResponse::ok(BackendType::LocalPython);
     }
 
+    // Nested Docker-in-Docker can't reliably bind-mount project paths (the
+    // sibling container only sees the outer host's volume sources), so when
+    // DevFlow itself is containerized, prefer a backend that doesn't depend
+    // on mounting: a local install if possible, otherwise a remote backend.
+    if status.running_in_container {
+        if status.python_available {
+            return CommandResponse::ok(BackendType::LocalPython);
+        }
+        return CommandResponse::ok(BackendType::Remote);
+    }
+
     if status.docker_available && status.docker_running {
         return CommandResponse::ok(BackendType::Docker);
     }
@@ -537,3 +910,116 @@ pub fn start_wsl(distro: String) -> CommandResponse<()> {
         Err(e) => CommandResponse::err(e),
     }
 }
+
+/// Get the current lifecycle status of a Docker-backend container: whether
+/// it exists, is running, its health check result, and the image it was
+/// created from.
+#[tauri::command]
+pub fn get_docker_backend_status(config: BackendConfig) -> CommandResponse<DockerBackendStatus> {
+    let container_name = config
+        .container_name
+        .unwrap_or_else(|| "devflow-backend".to_string());
+    CommandResponse::ok(docker_backend_status(&container_name))
+}
+
+/// Pull the Docker backend image, streaming per-layer progress as
+/// `install-log` events so the same install-log view used by
+/// `install_backend_with_logs` can show it.
+#[tauri::command]
+pub fn pull_backend_image(app: AppHandle, image: Option<String>) -> CommandResponse<()> {
+    let image_ref = image.unwrap_or_else(|| DEFAULT_DOCKER_IMAGE.to_string());
+    emit_install_log(&app, "info", &format!("Pulling {}", image_ref), None);
+
+    let result = pull_docker_image_with_layer_progress(&image_ref, |progress| {
+        emit_install_log(
+            &app,
+            "info",
+            &format!("{}: {:?}", progress.layer_id, progress.status),
+            None,
+        );
+    });
+
+    if result.success {
+        emit_install_log(&app, "success", &result.message, None);
+        CommandResponse::ok(())
+    } else {
+        emit_install_log(&app, "error", &result.message, None);
+        CommandResponse::err(result.message)
+    }
+}
+
+/// Live `docker logs -f` follows started by `stream_backend_container_logs`,
+/// keyed by the token handed back to the frontend, so they can be cancelled
+/// individually with `stop_backend_container_logs`.
+#[derive(Default)]
+pub struct DockerLogStreams {
+    children: Mutex<HashMap<String, Child>>,
+    next_id: AtomicU64,
+}
+
+/// A line of Docker container log output, streamed to the frontend.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DockerLogLine {
+    pub stream: String,
+    pub line: String,
+}
+
+/// Follow a Docker backend container's logs, emitting each line on
+/// `docker-container-log:<token>` until `stop_backend_container_logs` is
+/// called or the container stops.
+#[tauri::command]
+pub fn stream_backend_container_logs(
+    app: AppHandle,
+    streams: State<DockerLogStreams>,
+    container_name: String,
+) -> CommandResponse<String> {
+    let mut child = match Command::new("docker")
+        .args(["logs", "-f", "--tail", "100", &container_name])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return CommandResponse::err(format!("Failed to run docker logs: {}", e)),
+    };
+
+    let stdout = match child.stdout.take() {
+        Some(stdout) => stdout,
+        None => return CommandResponse::err("Failed to capture docker logs output".to_string()),
+    };
+
+    let token = format!(
+        "dlog-{}",
+        streams.next_id.fetch_add(1, Ordering::Relaxed)
+    );
+    streams.children.lock().unwrap().insert(token.clone(), child);
+
+    let event_name = format!("docker-container-log:{}", token);
+    std::thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        for line in reader.lines().map_while(Result::ok) {
+            let _ = app.emit(
+                &event_name,
+                DockerLogLine {
+                    stream: "stdout".to_string(),
+                    line,
+                },
+            );
+        }
+    });
+
+    CommandResponse::ok(token)
+}
+
+/// Stop a container log follow started with `stream_backend_container_logs`.
+#[tauri::command]
+pub fn stop_backend_container_logs(streams: State<DockerLogStreams>, token: String) -> CommandResponse<()> {
+    match streams.children.lock().unwrap().remove(&token) {
+        Some(mut child) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            CommandResponse::ok(())
+        }
+        None => CommandResponse::err(format!("Unknown log stream token: {}", token)),
+    }
+}
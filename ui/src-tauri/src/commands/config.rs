@@ -182,6 +182,19 @@ pub fn get_global_config(bridge: State<Arc<BridgeManager>>) -> CommandResponse<V
     }
 }
 
+/// Resolve the effective global configuration in pure Rust - built-in
+/// defaults, `~/.devflow`, an optional project's `.devflow.toml`, then env
+/// vars - without needing the Python bridge up. Used by the setup wizard to
+/// show (and validate) config before a backend has been started.
+#[tauri::command]
+pub fn get_resolved_global_config(project_path: Option<String>) -> CommandResponse<GlobalConfig> {
+    let project_dir = project_path.as_ref().map(std::path::Path::new);
+    match crate::backend::resolve_global_config(project_dir) {
+        Ok(config) => CommandResponse::ok(config),
+        Err(e) => CommandResponse::err(e.to_string()),
+    }
+}
+
 /// Get project configuration
 #[tauri::command]
 pub fn get_project_config(
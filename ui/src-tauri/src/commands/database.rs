@@ -1,4 +1,4 @@
-use super::{bridge_call, CommandResponse};
+use super::{bridge_call, bridge_call_checked, CommandResponse};
 use crate::bridge::BridgeManager;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -81,7 +81,10 @@ pub fn run_migrations(
     }
 }
 
-/// Rollback migrations
+/// Rollback migrations. Uses `bridge_call_checked` rather than `bridge_call`
+/// so a backend that hasn't advertised `db.rollback` during its handshake
+/// (an older Python bridge, or a degraded negotiated capability set) fails
+/// fast with a clean "unsupported" message instead of round-tripping first.
 #[tauri::command]
 pub fn rollback_migrations(
     bridge: State<Arc<BridgeManager>>,
@@ -91,7 +94,7 @@ pub fn rollback_migrations(
     dry_run: bool,
     force: bool,
 ) -> CommandResponse<Value> {
-    match bridge_call(
+    match bridge_call_checked(
         &bridge,
         "db.rollback",
         Some(json!({
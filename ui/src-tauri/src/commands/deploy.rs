@@ -1,9 +1,10 @@
-use super::{bridge_call, CommandResponse};
+use super::{bridge_call, bridge_call_checked, CommandResponse};
 use crate::bridge::BridgeManager;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::sync::Arc;
-use tauri::State;
+use std::thread;
+use tauri::{AppHandle, Emitter, State};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeployStatus {
@@ -39,6 +40,17 @@ pub struct DeployServiceResult {
     pub error: Option<String>,
 }
 
+/// One service's outcome from `deploy_services`, a coordinated multi-service
+/// deploy sent as a single JSON-RPC batch instead of N sequential `deploy`
+/// round trips.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceDeployOutcome {
+    pub service: String,
+    pub success: bool,
+    pub data: Option<Value>,
+    pub error: Option<String>,
+}
+
 /// Get deployment status
 #[tauri::command]
 pub fn get_deploy_status(
@@ -87,6 +99,57 @@ pub fn deploy(
     }
 }
 
+/// Deploy several services in one round trip: sends a `deploy.deploy` call
+/// per service as a single JSON-RPC batch instead of N sequential ones, and
+/// reports per-service success/failure back to the frontend.
+#[tauri::command]
+pub fn deploy_services(
+    bridge: State<Arc<BridgeManager>>,
+    project_path: String,
+    environment: String,
+    services: Vec<String>,
+    migrate: bool,
+    dry_run: bool,
+) -> CommandResponse<Vec<ServiceDeployOutcome>> {
+    let calls = services
+        .iter()
+        .map(|service| {
+            (
+                "deploy.deploy",
+                Some(json!({
+                    "path": project_path,
+                    "environment": environment,
+                    "service": service,
+                    "migrate": migrate,
+                    "dry_run": dry_run
+                })),
+            )
+        })
+        .collect();
+
+    let outcomes = bridge
+        .call_batch(calls)
+        .into_iter()
+        .zip(services)
+        .map(|(result, service)| match result {
+            Ok(data) => ServiceDeployOutcome {
+                service,
+                success: true,
+                data: Some(data),
+                error: None,
+            },
+            Err(e) => ServiceDeployOutcome {
+                service,
+                success: false,
+                data: None,
+                error: Some(format!("Bridge error: {}", e)),
+            },
+        })
+        .collect();
+
+    CommandResponse::ok(outcomes)
+}
+
 /// Rollback deployment
 #[tauri::command]
 pub fn rollback_deploy(
@@ -95,7 +158,7 @@ pub fn rollback_deploy(
     environment: String,
     service: Option<String>,
 ) -> CommandResponse<Value> {
-    match bridge_call(
+    match bridge_call_checked(
         &bridge,
         "deploy.rollback",
         Some(json!({
@@ -135,6 +198,54 @@ pub fn get_deploy_logs(
     }
 }
 
+/// Stream deployment logs live via the `deploy-log` Tauri event, so
+/// `follow: true` actually follows instead of returning one snapshot.
+///
+/// Returns the subscription id, which can be passed to
+/// `stop_deploy_log_stream` to end the stream early.
+#[tauri::command]
+pub fn stream_deploy_logs(
+    app: AppHandle,
+    bridge: State<Arc<BridgeManager>>,
+    project_path: String,
+    environment: String,
+    service: String,
+) -> CommandResponse<u64> {
+    let subscribed = bridge.subscribe(
+        "deploy.logs.stream",
+        Some(json!({
+            "path": project_path,
+            "environment": environment,
+            "service": service
+        })),
+    );
+
+    let (subscription_id, rx) = match subscribed {
+        Ok(subscription) => subscription,
+        Err(e) => return CommandResponse::err(format!("Bridge error: {}", e)),
+    };
+
+    thread::spawn(move || {
+        while let Ok(notification) = rx.recv() {
+            let _ = app.emit("deploy-log", notification);
+        }
+    });
+
+    CommandResponse::ok(subscription_id)
+}
+
+/// Stop a log stream started with `stream_deploy_logs`.
+#[tauri::command]
+pub fn stop_deploy_log_stream(
+    bridge: State<Arc<BridgeManager>>,
+    subscription_id: u64,
+) -> CommandResponse<()> {
+    match bridge.unsubscribe("deploy.logs.unsubscribe", subscription_id) {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(format!("Bridge error: {}", e)),
+    }
+}
+
 /// SSH into deployment environment
 #[tauri::command]
 pub fn get_ssh_command(
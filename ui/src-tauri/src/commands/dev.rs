@@ -3,7 +3,8 @@ use crate::bridge::BridgeManager;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::sync::Arc;
-use tauri::State;
+use std::thread;
+use tauri::{AppHandle, Emitter, State};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DevStatus {
@@ -134,6 +135,178 @@ pub fn get_dev_logs(
     }
 }
 
+/// Start tailing service logs as a stream of Tauri events instead of
+/// buffering a single response. Returns a token immediately; log lines are
+/// emitted on `dev:logs:<token>` until `cancel_dev_logs` is called or the
+/// bridge closes the subscription.
+#[tauri::command]
+pub fn stream_dev_logs(
+    app: AppHandle,
+    bridge: State<Arc<BridgeManager>>,
+    project_path: String,
+    service: String,
+    tail: Option<u32>,
+) -> CommandResponse<String> {
+    let subscribed = bridge.subscribe(
+        "dev.logs.stream",
+        Some(json!({ "path": project_path, "service": service, "tail": tail })),
+    );
+    let (subscription_id, rx) = match subscribed {
+        Ok(subscription) => subscription,
+        Err(e) => return CommandResponse::err(format!("Bridge error: {}", e)),
+    };
+
+    let token = bridge.register_stream("dev.logs.unsubscribe", subscription_id);
+    let event_name = format!("dev:logs:{}", token);
+    thread::spawn(move || {
+        while let Ok(notification) = rx.recv() {
+            let _ = app.emit(&event_name, notification);
+        }
+    });
+
+    CommandResponse::ok(token)
+}
+
+/// Stop a log tail started with `stream_dev_logs`.
+#[tauri::command]
+pub fn cancel_dev_logs(bridge: State<Arc<BridgeManager>>, token: String) -> CommandResponse<()> {
+    match bridge.cancel_stream(&token) {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(format!("Bridge error: {}", e)),
+    }
+}
+
+/// Follow development environment status as it changes, using the same
+/// token/channel mechanism as `stream_dev_logs`. Status snapshots are
+/// emitted on `dev:status:<token>`.
+#[tauri::command]
+pub fn stream_dev_status(
+    app: AppHandle,
+    bridge: State<Arc<BridgeManager>>,
+    project_path: String,
+) -> CommandResponse<String> {
+    let subscribed = bridge.subscribe("dev.status.stream", Some(json!({ "path": project_path })));
+    let (subscription_id, rx) = match subscribed {
+        Ok(subscription) => subscription,
+        Err(e) => return CommandResponse::err(format!("Bridge error: {}", e)),
+    };
+
+    let token = bridge.register_stream("dev.status.unsubscribe", subscription_id);
+    let event_name = format!("dev:status:{}", token);
+    thread::spawn(move || {
+        while let Ok(notification) = rx.recv() {
+            let _ = app.emit(&event_name, notification);
+        }
+    });
+
+    CommandResponse::ok(token)
+}
+
+/// Stop a status follow started with `stream_dev_status`.
+#[tauri::command]
+pub fn cancel_dev_status(bridge: State<Arc<BridgeManager>>, token: String) -> CommandResponse<()> {
+    match bridge.cancel_stream(&token) {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(format!("Bridge error: {}", e)),
+    }
+}
+
+/// Start an interactive exec session (shell, REPL, or any TTY-attached
+/// process) instead of running a one-shot command. Opens a bidirectional
+/// stream to the container process and returns a session id immediately;
+/// stdout/stderr chunks are emitted on `dev:exec:<session>` as they arrive.
+#[tauri::command]
+pub fn start_exec_session(
+    app: AppHandle,
+    bridge: State<Arc<BridgeManager>>,
+    project_path: String,
+    service: String,
+    command: Vec<String>,
+    tty: bool,
+) -> CommandResponse<String> {
+    let subscribed = bridge.subscribe(
+        "dev.exec_interactive.start",
+        Some(json!({
+            "path": project_path,
+            "service": service,
+            "command": command,
+            "tty": tty
+        })),
+    );
+    let (subscription_id, rx) = match subscribed {
+        Ok(subscription) => subscription,
+        Err(e) => return CommandResponse::err(format!("Bridge error: {}", e)),
+    };
+
+    let session_id = bridge.register_exec_session(subscription_id, service, command, tty);
+    let event_name = format!("dev:exec:{}", session_id);
+    thread::spawn(move || {
+        while let Ok(chunk) = rx.recv() {
+            let _ = app.emit(&event_name, chunk);
+        }
+    });
+
+    CommandResponse::ok(session_id)
+}
+
+/// Forward a chunk of keystrokes to an exec session's stdin.
+#[tauri::command]
+pub fn write_exec_stdin(
+    bridge: State<Arc<BridgeManager>>,
+    session: String,
+    data: String,
+) -> CommandResponse<Value> {
+    let Some(subscription_id) = bridge.exec_session_subscription_id(&session) else {
+        return CommandResponse::err(format!("Unknown exec session: {}", session));
+    };
+    match bridge_call(
+        &bridge,
+        "dev.exec_interactive.stdin",
+        Some(json!({ "session": subscription_id, "data": data })),
+    ) {
+        Ok(data) => CommandResponse::ok(data),
+        Err(e) => CommandResponse::err(e),
+    }
+}
+
+/// Propagate a terminal resize to an exec session.
+#[tauri::command]
+pub fn resize_exec_tty(
+    bridge: State<Arc<BridgeManager>>,
+    session: String,
+    rows: u16,
+    cols: u16,
+) -> CommandResponse<Value> {
+    let Some(subscription_id) = bridge.exec_session_subscription_id(&session) else {
+        return CommandResponse::err(format!("Unknown exec session: {}", session));
+    };
+    match bridge_call(
+        &bridge,
+        "dev.exec_interactive.resize",
+        Some(json!({ "session": subscription_id, "rows": rows, "cols": cols })),
+    ) {
+        Ok(data) => CommandResponse::ok(data),
+        Err(e) => CommandResponse::err(e),
+    }
+}
+
+/// Tear down an interactive exec session.
+#[tauri::command]
+pub fn close_exec_session(bridge: State<Arc<BridgeManager>>, session: String) -> CommandResponse<()> {
+    match bridge.close_exec_session(&session) {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(format!("Bridge error: {}", e)),
+    }
+}
+
+/// List live interactive exec sessions.
+#[tauri::command]
+pub fn list_exec_sessions(
+    bridge: State<Arc<BridgeManager>>,
+) -> CommandResponse<Vec<crate::bridge::ExecSessionInfo>> {
+    CommandResponse::ok(bridge.list_exec_sessions())
+}
+
 /// Execute command in container
 #[tauri::command]
 pub fn exec_in_container(
@@ -176,14 +349,66 @@ pub fn reset_dev(
     }
 }
 
-/// Run development setup
+/// Run development setup as a streaming, observable run instead of a single
+/// blocking RPC. Opens a subscription on the multi-step backend setup and
+/// returns a token immediately; each `SetupStep` transition (pending ->
+/// running -> completed/failed) is emitted on `dev:setup:<token>`, followed
+/// by a final summary event carrying overall success and the ordered list
+/// of steps. The run is also tracked in `BridgeManager` so a reconnecting UI
+/// can call `get_dev_setup_progress` to fetch the steps completed so far
+/// instead of losing all progress on a page reload.
 #[tauri::command]
 pub fn setup_dev(
+    app: AppHandle,
     bridge: State<Arc<BridgeManager>>,
     project_path: String,
+) -> CommandResponse<String> {
+    let subscribed = bridge.subscribe("dev.setup.stream", Some(json!({ "path": project_path })));
+    let (subscription_id, rx) = match subscribed {
+        Ok(subscription) => subscription,
+        Err(e) => return CommandResponse::err(format!("Bridge error: {}", e)),
+    };
+
+    let token = bridge.register_setup_run(subscription_id);
+    let event_name = format!("dev:setup:{}", token);
+    let bridge_arc = Arc::clone(&bridge);
+    let run_token = token.clone();
+    thread::spawn(move || {
+        while let Ok(notification) = rx.recv() {
+            let finished = notification
+                .get("final")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            bridge_arc.record_setup_step(&run_token, notification.clone(), finished);
+            let _ = app.emit(&event_name, notification);
+            if finished {
+                break;
+            }
+        }
+    });
+
+    CommandResponse::ok(token)
+}
+
+/// Fetch the `SetupStep`s completed so far for a run started with
+/// `setup_dev`, and whether it has finished, so a reconnecting UI can catch
+/// up instead of losing progress made before a page reload.
+#[tauri::command]
+pub fn get_dev_setup_progress(
+    bridge: State<Arc<BridgeManager>>,
+    token: String,
 ) -> CommandResponse<Value> {
-    match bridge_call(&bridge, "dev.setup", Some(json!({ "path": project_path }))) {
-        Ok(data) => CommandResponse::ok(data),
-        Err(e) => CommandResponse::err(e),
+    match bridge.setup_run_steps(&token) {
+        Some((steps, finished)) => CommandResponse::ok(json!({ "steps": steps, "finished": finished })),
+        None => CommandResponse::err(format!("Unknown setup run: {}", token)),
+    }
+}
+
+/// Abort a `setup_dev` run in progress.
+#[tauri::command]
+pub fn cancel_dev_setup(bridge: State<Arc<BridgeManager>>, token: String) -> CommandResponse<()> {
+    match bridge.cancel_setup_run(&token) {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(format!("Bridge error: {}", e)),
     }
 }
@@ -1,4 +1,4 @@
-use super::{bridge_call, CommandResponse};
+use super::{bridge_call, bridge_call_checked, CommandResponse};
 use crate::bridge::BridgeManager;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -123,7 +123,7 @@ pub fn regenerate_certs(
     bridge: State<Arc<BridgeManager>>,
     domains: Option<Vec<String>>,
 ) -> CommandResponse<Value> {
-    match bridge_call(
+    match bridge_call_checked(
         &bridge,
         "infra.regenerate_certs",
         Some(json!({ "domains": domains })),
@@ -12,7 +12,36 @@ use crate::bridge::BridgeManager;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
-use tauri::State;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
+
+/// How often the health monitor pings the bridge when the connection is up.
+const HEALTH_PROBE_INTERVAL: Duration = Duration::from_secs(5);
+/// Starting point for the exponential reconnect backoff, doubled per attempt.
+const BASE_RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound on the exponential reconnect backoff.
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Add up to +/-20% jitter to a backoff duration, so multiple windows (or a
+/// window and a resumed session) recovering from the same outage don't all
+/// redial in lockstep. Dependency-free since this crate has no `rand`: draws
+/// from the low bits of the current time instead.
+fn jittered_backoff(backoff: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0) as u64;
+
+    let millis = backoff.as_millis() as u64;
+    let jitter_range = millis / 5;
+    if jitter_range == 0 {
+        return backoff;
+    }
+
+    let jitter = nanos % (jitter_range * 2 + 1);
+    Duration::from_millis(millis.saturating_sub(jitter_range).saturating_add(jitter))
+}
 
 /// Standard response wrapper for all commands
 #[derive(Debug, Serialize, Deserialize)]
@@ -40,17 +69,54 @@ impl<T> CommandResponse<T> {
     }
 }
 
-/// Helper to call bridge RPC method and handle errors
+/// Helper to call bridge RPC method and handle errors. Checks the active
+/// capability set first, so a window denied a method gets a clean
+/// "permission denied" instead of reaching the bridge at all.
 pub fn bridge_call(
     bridge: &State<Arc<BridgeManager>>,
     method: &str,
     params: Option<Value>,
 ) -> Result<Value, String> {
+    if !crate::capabilities::CapabilitySet::load().allows(method) {
+        return Err("permission denied".to_string());
+    }
     bridge
         .call(method, params)
         .map_err(|e| format!("Bridge error: {}", e))
 }
 
+/// Like `bridge_call`, but first checks the handshake-advertised method set
+/// so an unsupported method fails fast with a clean message instead of a
+/// confusing mid-call RPC error.
+pub fn bridge_call_checked(
+    bridge: &State<Arc<BridgeManager>>,
+    method: &str,
+    params: Option<Value>,
+) -> Result<Value, String> {
+    if !crate::capabilities::CapabilitySet::load().allows(method) {
+        return Err("permission denied".to_string());
+    }
+    bridge
+        .call_checked(method, params)
+        .map_err(|e| format!("Bridge error: {}", e))
+}
+
+/// Path the global backend config is currently being loaded from (honoring
+/// `--config`/`DEVFLOW_BACKEND_CONFIG` overrides), so the UI can show which
+/// file is in effect.
+#[tauri::command]
+pub fn get_active_config_path() -> CommandResponse<String> {
+    CommandResponse::ok(crate::backend::GlobalBackendConfig::active_config_path())
+}
+
+/// The bridge RPC methods this window is currently permitted to invoke, for
+/// the UI to reflect (e.g. hiding a disabled "Exec" button when the active
+/// backend isn't Docker/WSL2).
+#[tauri::command]
+pub fn get_active_capabilities() -> CommandResponse<Vec<String>> {
+    CommandResponse::ok(crate::capabilities::CapabilitySet::load().allowed_methods())
+}
+
 /// Bridge status command
 #[tauri::command]
 pub fn get_bridge_status(bridge: State<Arc<BridgeManager>>) -> CommandResponse<String> {
@@ -77,3 +143,146 @@ pub fn stop_bridge(bridge: State<Arc<BridgeManager>>) -> CommandResponse<()> {
     bridge.stop();
     CommandResponse::ok(())
 }
+
+/// Per-method RPC invocation counts and average durations over every
+/// recorded call, for a
+/// bridge "connection inspector" view.
+#[tauri::command]
+pub fn get_bridge_stats(
+    bridge: State<Arc<BridgeManager>>,
+) -> CommandResponse<Vec<crate::bridge::MethodStats>> {
+    CommandResponse::ok(bridge.stats())
+}
+
+/// Calls currently in flight on the bridge.
+#[tauri::command]
+pub fn get_bridge_connections(
+    bridge: State<Arc<BridgeManager>>,
+) -> CommandResponse<Vec<crate::bridge::BridgeConnection>> {
+    CommandResponse::ok(bridge.connections())
+}
+
+/// Abandon a stuck in-flight call and free its RPC slot.
+#[tauri::command]
+pub fn kill_bridge_call(bridge: State<Arc<BridgeManager>>, call_id: u64) -> CommandResponse<()> {
+    match bridge.kill_call(call_id) {
+        Ok(()) => CommandResponse::ok(()),
+        Err(e) => CommandResponse::err(format!("Bridge error: {}", e)),
+    }
+}
+
+/// Current supervised connection health: mode, uptime, last error, and
+/// reconnect attempt count.
+#[tauri::command]
+pub fn get_bridge_connection_status(
+    bridge: State<Arc<BridgeManager>>,
+) -> CommandResponse<crate::bridge::ConnectionStatus> {
+    CommandResponse::ok(bridge.connection_status())
+}
+
+/// Backend health for the active `auto_start` backend: connection state
+/// (Connected/Degraded/Reconnecting/Down), uptime, and consecutive failure
+/// count. Same data as `get_bridge_connection_status`, named for the backend
+/// health dashboard rather than the connection inspector.
+#[tauri::command]
+pub fn get_backend_health(
+    bridge: State<Arc<BridgeManager>>,
+) -> CommandResponse<crate::bridge::ConnectionStatus> {
+    CommandResponse::ok(bridge.connection_status())
+}
+
+/// Ask the active auto-start backend to come back up: restarts the Docker
+/// container or WSL2 service the active profile points at (a no-op for
+/// LocalPython/Remote, which the bridge's own subprocess-respawn/redial
+/// already covers).
+fn restart_auto_start_backend() {
+    let active = match crate::backend::GlobalBackendConfig::load().active_config() {
+        Some(config) if config.auto_start => config,
+        _ => return,
+    };
+
+    match active.backend_type {
+        crate::backend::BackendType::Docker => {
+            if let Some(name) = &active.container_name {
+                let result = crate::backend::installer::start_docker_container(name, active.tcp_port());
+                if !result.success {
+                    log::warn!("Docker container restart failed: {}", result.message);
+                }
+            }
+        }
+        crate::backend::BackendType::Wsl2 => {
+            if let Some(distro) = &active.wsl_distro {
+                let result = crate::backend::installer::start_wsl_service(distro, active.tcp_port());
+                if !result.success {
+                    log::warn!("WSL2 service restart failed: {}", result.message);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Start the background health supervisor: pings the bridge on an interval,
+/// transparently reconnects with exponential backoff if it drops, and emits
+/// `bridge:status` whenever the up/down status changes. A no-op if the
+/// monitor is already running.
+#[tauri::command]
+pub fn start_bridge_health_monitor(app: AppHandle, bridge: State<Arc<BridgeManager>>) -> CommandResponse<()> {
+    let bridge = Arc::clone(&bridge);
+    if !bridge.try_start_health_monitor() {
+        return CommandResponse::ok(());
+    }
+
+    thread::spawn(move || loop {
+        thread::sleep(HEALTH_PROBE_INTERVAL);
+
+        if bridge.reap_subprocess() {
+            log::warn!("Bridge subprocess self-terminated; treating as a health-probe failure");
+        }
+
+        match bridge.call("system.version", None) {
+            Ok(_) => {
+                if bridge.mark_health(true, None) {
+                    bridge.reset_reconnect_attempts();
+                    let _ = app.emit("bridge:status", bridge.connection_status());
+                    let _ = app.emit("backend-health-changed", bridge.connection_status());
+                }
+            }
+            Err(e) => {
+                if bridge.mark_health(false, Some(e.to_string())) {
+                    let _ = app.emit("bridge:status", bridge.connection_status());
+                    let _ = app.emit("backend-health-changed", bridge.connection_status());
+                }
+
+                let attempt = bridge.record_reconnect_attempt();
+                let backoff = jittered_backoff(
+                    Duration::from_millis(
+                        BASE_RECONNECT_BACKOFF.as_millis() as u64 * (1u64 << attempt.saturating_sub(1).min(6)),
+                    )
+                    .min(MAX_RECONNECT_BACKOFF),
+                );
+                log::warn!("Bridge health probe failed ({}), reconnecting in {:?}", e, backoff);
+                thread::sleep(backoff);
+
+                bridge.set_reconnecting(true);
+                let _ = app.emit("backend-health-changed", bridge.connection_status());
+                restart_auto_start_backend();
+
+                match bridge.reconnect() {
+                    Ok(()) => {
+                        log::info!("Bridge reconnected after {} attempt(s)", attempt);
+                        if bridge.mark_health(true, None) {
+                            bridge.reset_reconnect_attempts();
+                        }
+                    }
+                    Err(e) => log::warn!("Bridge reconnect attempt {} failed: {}", attempt, e),
+                }
+                bridge.set_reconnecting(false);
+                let _ = app.emit("bridge:status", bridge.connection_status());
+                let _ = app.emit("backend-health-changed", bridge.connection_status());
+            }
+        }
+    });
+
+    CommandResponse::ok(())
+}
@@ -2,8 +2,12 @@ use super::{bridge_call, CommandResponse};
 use crate::bridge::BridgeManager;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::sync::Arc;
-use tauri::State;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SecretInfo {
@@ -40,7 +44,7 @@ pub struct SyncStepResult {
     pub error: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerifyResult {
     pub success: bool,
     pub in_sync: u32,
@@ -48,7 +52,7 @@ pub struct VerifyResult {
     pub results: Vec<VerifyStepResult>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerifyStepResult {
     pub secret: String,
     pub status: String, // "in_sync" | "out_of_sync" | "missing" | "error"
@@ -145,6 +149,50 @@ pub fn export_secrets(
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EncryptedExportResult {
+    pub output_path: String,
+    pub recipient_count: u32,
+    pub key_ids: Vec<String>,
+}
+
+/// Export secrets as a SOPS/age-encrypted file instead of plaintext, so the
+/// result is safe to commit. Requires at least one of `recipients` (age
+/// public keys or PGP fingerprints) or `key_file` so `secrets.export_encrypted`
+/// never silently falls back to writing plaintext.
+#[tauri::command]
+pub fn export_secrets_encrypted(
+    bridge: State<Arc<BridgeManager>>,
+    project_path: String,
+    environment: String,
+    recipients: Vec<String>,
+    key_file: Option<String>,
+) -> CommandResponse<EncryptedExportResult> {
+    if recipients.is_empty() && key_file.is_none() {
+        return CommandResponse::err(
+            "at least one recipient or a key_file is required for an encrypted export",
+        );
+    }
+
+    match bridge_call(
+        &bridge,
+        "secrets.export_encrypted",
+        Some(json!({
+            "path": project_path,
+            "environment": environment,
+            "format": "sops",
+            "recipients": recipients,
+            "key_file": key_file
+        })),
+    ) {
+        Ok(data) => match serde_json::from_value::<EncryptedExportResult>(data) {
+            Ok(result) => CommandResponse::ok(result),
+            Err(e) => CommandResponse::err(format!("Invalid encrypted export response: {}", e)),
+        },
+        Err(e) => CommandResponse::err(e),
+    }
+}
+
 /// Get available secret providers
 #[tauri::command]
 pub fn get_secret_providers(bridge: State<Arc<BridgeManager>>) -> CommandResponse<Value> {
@@ -153,3 +201,128 @@ pub fn get_secret_providers(bridge: State<Arc<BridgeManager>>) -> CommandRespons
         Err(e) => CommandResponse::err(e),
     }
 }
+
+/// One running `watch_secrets` poll loop: a stop flag the command thread
+/// checks between polls, and the thread itself so shutdown can join it.
+struct SecretWatcher {
+    stop: Arc<AtomicBool>,
+    handle: thread::JoinHandle<()>,
+}
+
+/// Running secret-drift watchers, keyed by `(project_path, environment)`.
+/// Managed as Tauri app state so `watch_secrets`/`stop_watch_secrets` and
+/// the shutdown hook in `lib.rs` all share the same registry.
+#[derive(Default)]
+pub struct SecretWatcherRegistry {
+    watchers: Mutex<HashMap<(String, String), SecretWatcher>>,
+}
+
+impl SecretWatcherRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop every running watcher, joining each thread. Called when the app
+    /// is shutting down so no poll loop outlives the window it was serving.
+    pub fn shutdown_all(&self) {
+        let watchers = std::mem::take(&mut *self.watchers.lock().unwrap());
+        for (_, watcher) in watchers {
+            watcher.stop.store(true, Ordering::SeqCst);
+            let _ = watcher.handle.join();
+        }
+    }
+}
+
+/// Start watching `project_path`/`environment` for secret drift: polls
+/// `secrets.verify` every `interval_secs` and, whenever the out-of-sync
+/// count or any per-secret status changes from the previous poll, emits the
+/// new `VerifyResult` on the `secrets://drift` event. Replaces any watcher
+/// already running for the same project/environment.
+#[tauri::command]
+pub fn watch_secrets(
+    app: AppHandle,
+    bridge: State<Arc<BridgeManager>>,
+    watchers: State<Arc<SecretWatcherRegistry>>,
+    project_path: String,
+    environment: String,
+    interval_secs: u64,
+) -> CommandResponse<()> {
+    let key = (project_path.clone(), environment.clone());
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let bridge = Arc::clone(&bridge);
+    let stop_for_thread = Arc::clone(&stop);
+    let interval = Duration::from_secs(interval_secs.max(1));
+
+    let handle = thread::spawn(move || {
+        let mut last: Option<VerifyResult> = None;
+
+        while !stop_for_thread.load(Ordering::SeqCst) {
+            thread::sleep(interval);
+            if stop_for_thread.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let result = bridge
+                .call(
+                    "secrets.verify",
+                    Some(json!({
+                        "path": project_path,
+                        "environment": environment
+                    })),
+                )
+                .map_err(|e| e.to_string())
+                .and_then(|v| serde_json::from_value::<VerifyResult>(v).map_err(|e| e.to_string()));
+
+            let verify = match result {
+                Ok(verify) => verify,
+                Err(e) => {
+                    log::warn!("secrets.verify poll failed for {}/{}: {}", project_path, environment, e);
+                    continue;
+                }
+            };
+
+            let drifted = match &last {
+                Some(previous) => {
+                    previous.out_of_sync != verify.out_of_sync
+                        || previous.results != verify.results
+                }
+                None => verify.out_of_sync > 0,
+            };
+
+            if drifted {
+                let _ = app.emit("secrets://drift", &verify);
+            }
+            last = Some(verify);
+        }
+    });
+
+    let mut guard = watchers.watchers.lock().unwrap();
+    if let Some(previous) = guard.insert(key, SecretWatcher { stop, handle }) {
+        previous.stop.store(true, Ordering::SeqCst);
+        let _ = previous.handle.join();
+    }
+
+    CommandResponse::ok(())
+}
+
+/// Stop a watcher started with `watch_secrets` for the same project/environment.
+#[tauri::command]
+pub fn stop_watch_secrets(
+    watchers: State<Arc<SecretWatcherRegistry>>,
+    project_path: String,
+    environment: String,
+) -> CommandResponse<()> {
+    let removed = watchers
+        .watchers
+        .lock()
+        .unwrap()
+        .remove(&(project_path, environment));
+
+    if let Some(watcher) = removed {
+        watcher.stop.store(true, Ordering::SeqCst);
+        let _ = watcher.handle.join();
+    }
+
+    CommandResponse::ok(())
+}
@@ -1,14 +1,18 @@
+mod backend;
 mod bridge;
+mod capabilities;
 mod commands;
 
 use bridge::BridgeManager;
 use std::sync::Arc;
+use tauri::Manager;
 use tauri_plugin_log::{Target, TargetKind};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Initialize bridge manager
     let bridge_manager = Arc::new(BridgeManager::new());
+    let secret_watchers = Arc::new(commands::secrets::SecretWatcherRegistry::new());
 
     // Build log plugin with explicit targets
     let log_plugin = tauri_plugin_log::Builder::default()
@@ -20,8 +24,9 @@ pub fn run() {
         ])
         .build();
 
-    tauri::Builder::default()
+    let app = tauri::Builder::default()
         .manage(bridge_manager)
+        .manage(secret_watchers)
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
@@ -31,8 +36,17 @@ pub fn run() {
             commands::get_bridge_status,
             commands::start_bridge,
             commands::stop_bridge,
+            commands::get_bridge_stats,
+            commands::get_bridge_connections,
+            commands::kill_bridge_call,
+            commands::get_bridge_connection_status,
+            commands::start_bridge_health_monitor,
+            commands::get_backend_health,
+            commands::get_active_capabilities,
+            commands::get_active_config_path,
             // Config
             commands::config::get_global_config,
+            commands::config::get_resolved_global_config,
             commands::config::get_project_config,
             commands::config::update_global_config,
             commands::config::update_project_config,
@@ -62,24 +76,41 @@ pub fn run() {
             // Deploy
             commands::deploy::get_deploy_status,
             commands::deploy::deploy,
+            commands::deploy::deploy_services,
             commands::deploy::rollback_deploy,
             commands::deploy::get_deploy_logs,
+            commands::deploy::stream_deploy_logs,
+            commands::deploy::stop_deploy_log_stream,
             commands::deploy::get_ssh_command,
             // Secrets
             commands::secrets::list_secrets,
             commands::secrets::sync_secrets,
             commands::secrets::verify_secrets,
             commands::secrets::export_secrets,
+            commands::secrets::export_secrets_encrypted,
             commands::secrets::get_secret_providers,
+            commands::secrets::watch_secrets,
+            commands::secrets::stop_watch_secrets,
             // Development
             commands::dev::get_dev_status,
             commands::dev::start_dev,
             commands::dev::stop_dev,
             commands::dev::restart_dev_service,
             commands::dev::get_dev_logs,
+            commands::dev::stream_dev_logs,
+            commands::dev::cancel_dev_logs,
+            commands::dev::stream_dev_status,
+            commands::dev::cancel_dev_status,
             commands::dev::exec_in_container,
+            commands::dev::start_exec_session,
+            commands::dev::write_exec_stdin,
+            commands::dev::resize_exec_tty,
+            commands::dev::close_exec_session,
+            commands::dev::list_exec_sessions,
             commands::dev::reset_dev,
             commands::dev::setup_dev,
+            commands::dev::get_dev_setup_progress,
+            commands::dev::cancel_dev_setup,
             // System
             commands::system::run_doctor,
             commands::system::run_project_doctor,
@@ -107,6 +138,15 @@ pub fn run() {
             commands::setup::get_prerequisites_summary,
             commands::setup::refresh_platform_info,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application");
+
+    app.run(|app_handle, event| {
+        // Cancel any running secret-drift watchers so their poll threads
+        // don't outlive the window they were serving.
+        if let tauri::RunEvent::ExitRequested { .. } = event {
+            let watchers = app_handle.state::<Arc<commands::secrets::SecretWatcherRegistry>>();
+            watchers.shutdown_all();
+        }
+    });
 }